@@ -1,13 +1,14 @@
-advent_of_code::solution!(1);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 1);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let normal_digits = ["1", "2", "3", "4", "5", "6", "7", "8", "9"];
     let search_digits = [normal_digits].to_vec();
 
     Some(calculate_line_sum(input, &search_digits))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let named_digits = [
         "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
     ];
@@ -80,13 +81,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(142));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples_part2", DAY));
+        let result = part_two(&advent_of_code::template::read_file(
+            "examples_part2",
+            YEAR,
+            DAY,
+        ));
         assert_eq!(result, Some(281));
     }
 }
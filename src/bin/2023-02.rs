@@ -1,6 +1,7 @@
-advent_of_code::solution!(2);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 2);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let allowed_red = 12;
     let allowed_green = 13;
     let allowed_blue = 14;
@@ -20,7 +21,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(allowed_id_sum)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let mut cube_power_sum = 0;
     for line in input.lines() {
         let game = Game::from(line);
@@ -112,13 +113,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2286));
     }
 }
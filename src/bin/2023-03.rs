@@ -1,16 +1,17 @@
-advent_of_code::solution!(3);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 3);
 
 struct MarkChar {
     char: char,
     marked: bool,
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let rows = parse_input(input);
     Some(marked_number_sum(&rows))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let rows = parse_input(input);
     Some(calc_gear_ratio_sum(&rows))
 }
@@ -142,13 +143,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(467835));
     }
 }
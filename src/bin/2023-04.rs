@@ -1,6 +1,7 @@
-advent_of_code::solution!(4);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 4);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let mut sum = 0;
     for line in input.lines() {
         let (winning_numbers, my_numbers) = parse_line(line);
@@ -13,7 +14,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let line_count = input.lines().count();
     let mut card_counts = vec![1; line_count];
 
@@ -63,13 +64,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(30));
     }
 }
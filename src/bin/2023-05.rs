@@ -1,17 +1,11 @@
-advent_of_code::solution!(5);
-
-#[cfg(windows)]
-const EMPTY_LINE: &'static str = "\r\n\r\n";
-#[cfg(not(windows))]
-const EMPTY_LINE: &'static str = "\n\n";
-
-pub fn part_one(input: &str) -> Option<u64> {
-    let (seeds, mappers) = parse_input(input, &parse_seeds_line);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 5, parse_input);
 
+pub fn part_one(almanac: &Almanac) -> Option<u64> {
     let mut smallest_location = u64::MAX;
-    for seed in seeds {
+    for &seed in &almanac.seeds {
         let mut current_state = seed;
-        for mapper in &mappers {
+        for mapper in &almanac.mappers {
             current_state = mapper.map(current_state);
         }
         if current_state < smallest_location {
@@ -21,16 +15,14 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(smallest_location)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (seed_ranges, mappers) = parse_input(input, &parse_seed_ranges);
-
+pub fn part_two(almanac: &Almanac) -> Option<u64> {
     let mut smallest_location = u64::MAX;
-    for seed_range in seed_ranges {
+    for seed_range in almanac.seed_ranges() {
         // this is the brute force approach, but it's fast enough
         // we could be working with ranges instead of individual seeds
         for seed in seed_range.start..seed_range.end {
             let mut current_state = seed;
-            for mapper in &mappers {
+            for mapper in &almanac.mappers {
                 current_state = mapper.map(current_state);
             }
             if current_state < smallest_location {
@@ -42,6 +34,21 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(smallest_location)
 }
 
+pub struct Almanac {
+    seeds: Vec<u64>,
+    mappers: Vec<Mapper>,
+}
+
+impl Almanac {
+    /// In part two, the seeds line lists pairs of range starts and lengths.
+    fn seed_ranges(&self) -> Vec<Range> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| Range::new(pair[0], pair[0] + pair[1]))
+            .collect()
+    }
+}
+
 struct RangeMap {
     source_start: u64,
     destination_start: u64,
@@ -102,14 +109,14 @@ impl Mapper {
     }
 }
 
-fn parse_input<T>(input: &str, seed_parser: &dyn Fn(&str) -> T) -> (T, Vec<Mapper>) {
-    let mut newline_parts = input.split(EMPTY_LINE).skip_while(|line| line.is_empty());
-    let seeds = seed_parser(newline_parts.next().unwrap());
+fn parse_input(input: &Input) -> Almanac {
+    let mut newline_parts = input.paragraphs();
+    let seeds = parse_seeds_line(newline_parts.next().unwrap());
     let mut mappers = Vec::new();
     for line in newline_parts {
         mappers.push(Mapper::from(line));
     }
-    (seeds, mappers)
+    Almanac { seeds, mappers }
 }
 
 fn parse_seeds_line(line: &str) -> Vec<u64> {
@@ -123,19 +130,6 @@ fn parse_seeds_line(line: &str) -> Vec<u64> {
     seeds
 }
 
-fn parse_seed_ranges(line: &str) -> Vec<Range> {
-    let mut parts = line.split(": ");
-    parts.next();
-    let seeds_text = parts.next().unwrap();
-    let mut seeds = Vec::new();
-    let mut whitespace_parts = seeds_text.split_whitespace();
-    while let (Some(start), Some(length)) = (whitespace_parts.next(), whitespace_parts.next()) {
-        let start = start.parse::<u64>().unwrap();
-        seeds.push(Range::new(start, start + length.parse::<u64>().unwrap()));
-    }
-    seeds
-}
-
 struct Range {
     start: u64,
     end: u64,
@@ -153,13 +147,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(46));
     }
 }
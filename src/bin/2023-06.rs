@@ -1,6 +1,7 @@
-advent_of_code::solution!(6);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 6);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Input) -> Option<u64> {
     let races = read_input(input, &read_line_multi_races);
 
     let mut result = 1;
@@ -11,7 +12,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Input) -> Option<u64> {
     let races = read_input(input, &read_line_single_race);
 
     let mut result = 1;
@@ -85,13 +86,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(288));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(71503));
     }
 }
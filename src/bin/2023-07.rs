@@ -1,12 +1,13 @@
+use advent_of_code::template::Input;
 use std::cmp::Ordering;
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let mut hands = parse_input(input, 11);
     Some(get_value(&mut hands))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let mut hands = parse_input(input, 0);
     Some(get_value(&mut hands))
 }
@@ -154,13 +155,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(5905));
     }
 }
@@ -1,18 +1,19 @@
+use advent_of_code::template::Input;
 use num::integer::lcm;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Input) -> Option<u64> {
     let (instructions, nodes) = parse_input(input);
     let start_node = Rc::clone(nodes.get("AAA").unwrap());
 
     Some(get_steps_until(start_node, instructions, "ZZZ"))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Input) -> Option<u64> {
     let (instructions, nodes) = parse_input(input);
 
     let start_nodes: Vec<Rc<RefCell<Node>>> = nodes
@@ -133,13 +134,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples_part2", DAY));
+        let result = part_two(&advent_of_code::template::read_file(
+            "examples_part2",
+            YEAR,
+            DAY,
+        ));
         assert_eq!(result, Some(6));
     }
 }
@@ -1,6 +1,7 @@
-advent_of_code::solution!(9);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 9);
 
-pub fn part_one(input: &str) -> Option<i64> {
+pub fn part_one(input: &Input) -> Option<i64> {
     let mut sum = 0;
 
     for line in input.lines() {
@@ -11,7 +12,7 @@ pub fn part_one(input: &str) -> Option<i64> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
+pub fn part_two(input: &Input) -> Option<i64> {
     let mut sum = 0;
 
     for line in input.lines() {
@@ -47,13 +48,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2));
     }
 }
@@ -1,14 +1,11 @@
-use std::cell::RefCell;
-advent_of_code::solution!(10);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 10, Map::from);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let map = Map::from(input);
-    Some(map.find_furthest_distance_in_loop(false))
+pub fn part_one(map: &Map) -> Option<u32> {
+    Some(map.find_furthest_distance_in_loop())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let map = Map::from(input);
-    map.find_furthest_distance_in_loop(true);
+pub fn part_two(map: &Map) -> Option<u32> {
     Some(map.get_encircled_count())
 }
 
@@ -74,7 +71,7 @@ impl std::ops::Add<Direction> for Location {
 
 struct Tile {
     directions: Option<[Direction; 2]>,
-    is_solution_path: RefCell<bool>,
+    is_solution_path: bool,
 }
 
 impl Tile {
@@ -86,13 +83,13 @@ impl Tile {
     }
 }
 
-struct Map {
+pub struct Map {
     tiles: Vec<Vec<Tile>>,
     start: Location,
 }
 
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
+impl From<&Input> for Map {
+    fn from(value: &Input) -> Self {
         let mut tiles = Vec::new();
         let mut start = None;
         for (y, line) in value.lines().enumerate() {
@@ -104,16 +101,18 @@ impl From<&str> for Map {
                 }
                 row.push(Tile {
                     directions,
-                    is_solution_path: RefCell::new(false),
+                    is_solution_path: false,
                 });
             }
             tiles.push(row);
         }
 
-        Self {
+        let mut map = Self {
             tiles,
             start: start.unwrap(),
-        }
+        };
+        map.paint_solution_path();
+        map
     }
 }
 
@@ -150,34 +149,44 @@ impl Map {
         }
     }
 
-    fn find_furthest_distance_in_loop(&self, paint_solution_path: bool) -> u32 {
+    fn find_furthest_distance_in_loop(&self) -> u32 {
         for direction in Direction::START_DIRECTIONS.iter() {
             let mut distance = 1;
-            if self.try_apply_on_loop(self.start, *direction, &mut |_: &Tile| {
+            if self.try_apply_on_loop(self.start, *direction, &mut |_| {
                 distance += 1;
             }) {
-                if paint_solution_path {
-                    self.try_apply_on_loop(self.start, *direction, &mut |tile| {
-                        tile.is_solution_path.replace(true);
-                    });
-                }
                 return distance / 2;
             }
         }
         return 0;
     }
 
+    /// Marks the tiles of the loop, which part two needs to tell the inside from the outside.
+    fn paint_solution_path(&mut self) {
+        for direction in Direction::START_DIRECTIONS.iter() {
+            let mut solution_path = Vec::new();
+            if self.try_apply_on_loop(self.start, *direction, &mut |location| {
+                solution_path.push(location);
+            }) {
+                for location in solution_path {
+                    self.tiles[location.y][location.x].is_solution_path = true;
+                }
+                return;
+            }
+        }
+    }
+
     fn try_apply_on_loop(
         &self,
         from: Location,
         dir: Direction,
-        apply_to_tile: &mut dyn FnMut(&Tile) -> (),
+        apply_to_location: &mut dyn FnMut(Location) -> (),
     ) -> bool {
         let mut from = from;
         let mut dir = dir;
 
         loop {
-            apply_to_tile(&self.tiles[from.y][from.x]);
+            apply_to_location(from);
             if from + dir == self.start {
                 return true;
             }
@@ -214,7 +223,7 @@ impl Map {
         for row in self.tiles.iter() {
             let mut correct_path_count = 0;
             for tile in row.iter() {
-                if tile.is_solution_path.borrow().clone() {
+                if tile.is_solution_path {
                     // we have to check this for the edge-case that the path is on the row
                     if tile.is_top_vertical() {
                         correct_path_count += 1;
@@ -236,13 +245,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&Map::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples_part2", DAY));
+        let result = part_two(&Map::from(
+            advent_of_code::template::read_file("examples_part2", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(10));
     }
 }
@@ -1,11 +1,12 @@
-advent_of_code::solution!(11);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 11);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Input) -> Option<u64> {
     let points = parse_input(input, 1);
     Some(pair_distance_sum(&points))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Input) -> Option<u64> {
     let points = parse_input(input, 999999);
     Some(pair_distance_sum(&points))
 }
@@ -78,13 +79,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(374));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(82000210));
     }
 }
@@ -1,7 +1,8 @@
+use advent_of_code::template::Input;
 use std::collections::HashMap;
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Input) -> Option<u64> {
     let mut sum = 0;
     for line in input.lines() {
         let (remaining, required) = parse_line(line);
@@ -10,7 +11,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Input) -> Option<u64> {
     let mut sum = 0;
     for line in input.lines() {
         let (remaining, required) = parse_line(line);
@@ -135,19 +136,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_one_hard() {
-        let result = part_one("?###???????? 3,2,1");
+        let result = part_one(Input::new("?###???????? 3,2,1"));
         assert_eq!(result, Some(10))
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(525152));
     }
 }
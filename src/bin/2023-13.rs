@@ -1,12 +1,8 @@
+use advent_of_code::template::Input;
 use std::cmp::min;
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
-#[cfg(windows)]
-const EMPTY_LINE: &'static str = "\r\n\r\n";
-#[cfg(not(windows))]
-const EMPTY_LINE: &'static str = "\n\n";
-
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let patterns = parse(input);
     let mut result = 0;
 
@@ -27,7 +23,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let mut patterns = parse(input);
     let mut result = 0;
 
@@ -60,9 +56,9 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(result)
 }
 
-fn parse(input: &str) -> Vec<Vec<Vec<bool>>> {
+fn parse(input: &Input) -> Vec<Vec<Vec<bool>>> {
     input
-        .split(EMPTY_LINE)
+        .paragraphs()
         .map(|chunk| parse_chunk(chunk))
         .collect::<Vec<_>>()
 }
@@ -150,13 +146,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(405));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(400));
     }
 }
@@ -1,11 +1,12 @@
-advent_of_code::solution!(15);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 15);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let result = input.split(',').map(|part| custom_hash(part)).sum();
 
     Some(result)
 }
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let mut boxes: [Vec<Lens>; 256] = core::array::from_fn(|_| Vec::new());
     for instruction in input.split(',') {
         run_instruction(instruction, &mut boxes);
@@ -74,13 +75,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1320));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(145));
     }
 }
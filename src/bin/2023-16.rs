@@ -1,13 +1,14 @@
+use advent_of_code::template::Input;
 use std::cmp::max;
-advent_of_code::solution!(16);
+advent_of_code::solution!(2023, 16);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input) -> Option<u32> {
     let mut map = parse(input);
     move_light(&mut map, &Index::new(-1, 0), &Direction::Right);
     Some(count_energized_tiles(&map))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &Input) -> Option<u32> {
     let map = parse(input);
     let mut max_energy = 0;
 
@@ -175,13 +176,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(51));
     }
 }
@@ -1,25 +1,21 @@
+use advent_of_code::template::Input;
 use std::collections::BinaryHeap;
-advent_of_code::solution!(17);
+advent_of_code::solution!(2023, 17, Map::from);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let map = Map::from(input);
-    Some(map.shortest_path())
+pub fn part_one(map: &Map) -> Option<u32> {
+    Some(map.shortest_path(1, 4))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut map = Map::from(input);
-    map.set_distances(4, 11);
-    Some(map.shortest_path())
+pub fn part_two(map: &Map) -> Option<u32> {
+    Some(map.shortest_path(4, 11))
 }
 
-struct Map {
+pub struct Map {
     tiles: Vec<Vec<u8>>,
-    min_roll_distance: u8,
-    max_roll_distance: u8,
 }
 
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
+impl From<&Input> for Map {
+    fn from(value: &Input) -> Self {
         Map {
             tiles: value
                 .lines()
@@ -29,19 +25,12 @@ impl From<&str> for Map {
                         .collect()
                 })
                 .collect(),
-            min_roll_distance: 1,
-            max_roll_distance: 4,
         }
     }
 }
 
 impl Map {
-    fn set_distances(&mut self, min_roll_distance: u8, max_roll_distance: u8) {
-        self.min_roll_distance = min_roll_distance;
-        self.max_roll_distance = max_roll_distance;
-    }
-
-    fn shortest_path(&self) -> u32 {
+    fn shortest_path(&self, min_roll_distance: u8, max_roll_distance: u8) -> u32 {
         let mut visit_infos: Vec<Vec<Vec<State>>> =
             vec![vec![Vec::new(); self.tiles[0].len()]; self.tiles.len()];
 
@@ -61,7 +50,7 @@ impl Map {
 
         'outer: while let Some(SearchState { position, state }) = heap.pop() {
             if position == goal {
-                if state.movement.length() < self.min_roll_distance {
+                if state.movement.length() < min_roll_distance {
                     continue;
                 }
                 return state.cost;
@@ -76,7 +65,7 @@ impl Map {
                     continue;
                 }
                 if visit_state.movement.length() != state.movement.length() {
-                    if visit_state.movement.length() >= self.min_roll_distance
+                    if visit_state.movement.length() >= min_roll_distance
                         && visit_state.movement.length() <= state.movement.length()
                         && visit_state.cost <= state.cost
                     {
@@ -96,16 +85,40 @@ impl Map {
                 visit_info.push(state);
             }
 
-            if let Some(new_state) = self.get_moved_state(&state, &position, &Movement::Up(1)) {
+            if let Some(new_state) = self.get_moved_state(
+                &state,
+                &position,
+                &Movement::Up(1),
+                min_roll_distance,
+                max_roll_distance,
+            ) {
                 heap.push(new_state);
             }
-            if let Some(new_state) = self.get_moved_state(&state, &position, &Movement::Down(1)) {
+            if let Some(new_state) = self.get_moved_state(
+                &state,
+                &position,
+                &Movement::Down(1),
+                min_roll_distance,
+                max_roll_distance,
+            ) {
                 heap.push(new_state);
             }
-            if let Some(new_state) = self.get_moved_state(&state, &position, &Movement::Left(1)) {
+            if let Some(new_state) = self.get_moved_state(
+                &state,
+                &position,
+                &Movement::Left(1),
+                min_roll_distance,
+                max_roll_distance,
+            ) {
                 heap.push(new_state);
             }
-            if let Some(new_state) = self.get_moved_state(&state, &position, &Movement::Right(1)) {
+            if let Some(new_state) = self.get_moved_state(
+                &state,
+                &position,
+                &Movement::Right(1),
+                min_roll_distance,
+                max_roll_distance,
+            ) {
                 heap.push(new_state);
             }
         }
@@ -117,11 +130,13 @@ impl Map {
         state: &State,
         position: &Position,
         movement: &Movement,
+        min_roll_distance: u8,
+        max_roll_distance: u8,
     ) -> Option<SearchState> {
         if let Some(new_movement) =
             state
                 .movement
-                .change_movement(movement, self.min_roll_distance, self.max_roll_distance)
+                .change_movement(movement, min_roll_distance, max_roll_distance)
         {
             if let Some(new_position) =
                 position.move_in_dir(movement, self.tiles.len() - 1, self.tiles[0].len() - 1)
@@ -300,13 +315,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&Map::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(102));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&Map::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(94));
     }
 }
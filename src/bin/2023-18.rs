@@ -1,12 +1,13 @@
-advent_of_code::solution!(18);
+use advent_of_code::template::Input;
+advent_of_code::solution!(2023, 18);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Input) -> Option<u64> {
     let movements = parse_instructions_part1(input);
     let map = Map::from(&movements);
     Some(map.get_area())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Input) -> Option<u64> {
     let movements = parse_instructions_part2(input);
     let map = Map::from(&movements);
     Some(map.get_area())
@@ -160,13 +161,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(62));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(952408144115));
     }
 }
@@ -1,19 +1,17 @@
+use advent_of_code::template::Input;
 use std::collections::HashMap;
 use std::ops::Range;
-advent_of_code::solution!(19);
-
-#[cfg(windows)]
-const EMPTY_LINE: &'static str = "\r\n\r\n";
-#[cfg(not(windows))]
-const EMPTY_LINE: &'static str = "\n\n";
-
-pub fn part_one(input: &str) -> Option<u64> {
-    let (workflows, data) = parse_input(input);
-    Some(calculate_sum(workflows, data, &|data| data.start_sum()))
+advent_of_code::solution!(2023, 19, parse_input);
+
+pub fn part_one(system: &System) -> Option<u64> {
+    Some(calculate_sum(
+        &system.workflows,
+        system.data.clone(),
+        &|data| data.start_sum(),
+    ))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let workflows = parse_workflows(input.split(EMPTY_LINE).next().unwrap());
+pub fn part_two(system: &System) -> Option<u64> {
     let data = vec![Data {
         data: [
             1..4001, // x
@@ -22,11 +20,18 @@ pub fn part_two(input: &str) -> Option<u64> {
             1..4001, // s
         ],
     }];
-    Some(calculate_sum(workflows, data, &|data| data.field_count()))
+    Some(calculate_sum(&system.workflows, data, &|data| {
+        data.field_count()
+    }))
+}
+
+pub struct System {
+    workflows: HashMap<String, WorkFlow>,
+    data: Vec<Data>,
 }
 
 fn calculate_sum(
-    workflows: HashMap<&str, WorkFlow>,
+    workflows: &HashMap<String, WorkFlow>,
     data: Vec<Data>,
     sum_function: &dyn Fn(&Data) -> u64,
 ) -> u64 {
@@ -54,21 +59,21 @@ fn calculate_sum(
     sum
 }
 
-fn parse_input(input: &str) -> (HashMap<&str, WorkFlow>, Vec<Data>) {
-    let mut parts = input.split(EMPTY_LINE);
+fn parse_input(input: &Input) -> System {
+    let mut parts = input.paragraphs();
     let workflows = parse_workflows(parts.next().unwrap());
     let data = parse_data(parts.next().unwrap());
-    (workflows, data)
+    System { workflows, data }
 }
 
-fn parse_workflows<'a>(input: &'a str) -> HashMap<&'a str, WorkFlow> {
+fn parse_workflows(input: &str) -> HashMap<String, WorkFlow> {
     let mut workflows = HashMap::new();
     for line in input.lines() {
         let mut parts = line.split("{");
         let name = parts.next().unwrap();
         let data = parts.next().unwrap();
         let data = data.trim_end_matches("}");
-        workflows.insert(name, data.into());
+        workflows.insert(name.to_string(), data.into());
     }
 
     workflows
@@ -78,12 +83,12 @@ fn parse_data(input: &str) -> Vec<Data> {
     input.lines().map(|line| line.into()).collect()
 }
 
-struct WorkFlow<'a> {
-    rules: Vec<Rule<'a>>,
+struct WorkFlow {
+    rules: Vec<Rule>,
 }
 
-impl<'a> WorkFlow<'a> {
-    fn get_next_workflows(&self, data: Data) -> Vec<(Data, &'a str)> {
+impl WorkFlow {
+    fn get_next_workflows(&self, data: Data) -> Vec<(Data, &str)> {
         let mut output_data = Vec::new();
         let mut test_data = data;
 
@@ -102,8 +107,8 @@ impl<'a> WorkFlow<'a> {
     }
 }
 
-impl<'a> From<&'a str> for WorkFlow<'a> {
-    fn from(value: &'a str) -> Self {
+impl From<&str> for WorkFlow {
+    fn from(value: &str) -> Self {
         let mut rules = Vec::new();
         for line in value.split(',') {
             rules.push(line.into());
@@ -112,27 +117,27 @@ impl<'a> From<&'a str> for WorkFlow<'a> {
     }
 }
 
-struct Rule<'a> {
+struct Rule {
     check: Check,
     satisfy_value: SatisfyValue,
-    satisfy_result: &'a str,
+    satisfy_result: String,
 }
 
-impl<'a> Rule<'a> {
-    fn check(&self, data: Data) -> (Option<(Data, &'a str)>, Option<Data>) {
+impl Rule {
+    fn check(&self, data: Data) -> (Option<(Data, &str)>, Option<Data>) {
         let result = self
             .satisfy_value
             .get_allowed_and_blocked(data, &self.check);
         let mut accepted = None;
         if result.0.is_some() {
-            accepted = Some((result.0.unwrap(), self.satisfy_result));
+            accepted = Some((result.0.unwrap(), self.satisfy_result.as_str()));
         }
         (accepted, result.1)
     }
 }
 
-impl<'a> From<&'a str> for Rule<'a> {
-    fn from(value: &'a str) -> Self {
+impl From<&str> for Rule {
+    fn from(value: &str) -> Self {
         if value.contains(':') {
             let mut outer_parts = value.split(":");
             let left = outer_parts.next().unwrap();
@@ -145,7 +150,7 @@ impl<'a> From<&'a str> for Rule<'a> {
                 Rule {
                     check: Check::LessThan,
                     satisfy_value: (left, right).into(),
-                    satisfy_result: target,
+                    satisfy_result: target.to_string(),
                 }
             } else {
                 let mut inner_parts = left.split(">");
@@ -154,14 +159,14 @@ impl<'a> From<&'a str> for Rule<'a> {
                 Rule {
                     check: Check::GreaterThan,
                     satisfy_value: (left, right).into(),
-                    satisfy_result: target,
+                    satisfy_result: target.to_string(),
                 }
             }
         } else {
             Rule {
                 check: Check::None,
                 satisfy_value: SatisfyValue::None,
-                satisfy_result: value,
+                satisfy_result: value.to_string(),
             }
         }
     }
@@ -307,13 +312,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(167409079868000));
     }
 }
@@ -1,7 +1,8 @@
+use advent_of_code::template::Input;
 use std::collections::HashMap;
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Input) -> Option<u64> {
     let mut system = System::from(input);
     for _ in 0..1000 {
         system.push_button();
@@ -9,7 +10,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(system.low_pulses_sent * system.high_pulses_sent)
 }
 
-pub fn part_two(_: &str) -> Option<u32> {
+pub fn part_two(_: &Input) -> Option<u32> {
     // part 2 is solved by hand
     None
 }
@@ -41,8 +42,8 @@ impl System {
     }
 }
 
-impl From<&str> for System {
-    fn from(value: &str) -> Self {
+impl From<&Input> for System {
+    fn from(value: &Input) -> Self {
         let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
         let mut name_to_targets: HashMap<String, Vec<String>> = HashMap::new();
         for line in value.lines() {
@@ -182,19 +183,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(32000000));
     }
 
     #[test]
     fn test_part_one_second() {
-        let result = part_one(&advent_of_code::template::read_file("examples_part2", DAY));
+        let result = part_one(&advent_of_code::template::read_file(
+            "examples_extra",
+            YEAR,
+            DAY,
+        ));
         assert_eq!(result, Some(11687500));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -1,25 +1,44 @@
+use advent_of_code::template::params::{self, Params};
+use advent_of_code::template::Input;
 use std::collections::HashSet;
-advent_of_code::solution!(21);
+advent_of_code::solution!(2023, 21, params: Steps);
 
-#[cfg(test)]
-const REQUIRED_STEPS: usize = 6;
+pub struct Steps {
+    /// The number of steps the elf takes in part one.
+    steps: usize,
+}
 
-#[cfg(not(test))]
-const REQUIRED_STEPS: usize = 64;
+impl Params for Steps {
+    fn example() -> Self {
+        Self { steps: 6 }
+    }
+
+    fn real() -> Self {
+        Self { steps: 64 }
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "steps" => self.steps = params::parse_value(value)?,
+            _ => return Err(params::unknown(name, &["steps"])),
+        }
+        Ok(())
+    }
+}
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &Input, params: &Steps) -> Option<u32> {
     let (map, start) = parse(input);
     let mut indices = HashSet::new();
     indices.insert(start);
 
-    for _ in 0..REQUIRED_STEPS {
+    for _ in 0..params.steps {
         indices = execute_step(&map, indices);
     }
 
     Some(indices.len() as u32)
 }
 
-pub fn part_two(_: &str) -> Option<u32> {
+pub fn part_two(_: &Input, _: &Steps) -> Option<u32> {
     // start seems to be in the center of map
     // there seem to be "empty" horizontal and vertical "roads" in the input
     // so we somehow have to sum all the repeating tiles and take care of the edge cases of the diamond shape
@@ -134,13 +153,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &Steps::example(),
+        );
         assert_eq!(result, Some(16));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &Steps::example(),
+        );
         assert_eq!(result, None);
     }
 }
@@ -1,43 +1,41 @@
+use advent_of_code::template::Input;
 use std::collections::{HashMap, HashSet};
 use std::ops::RangeInclusive;
 use std::sync::atomic::{AtomicU32, Ordering};
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22, Map::from);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut map = Map::from(input);
-    Some(map.fall_and_get_deletable_counts())
+pub fn part_one(map: &Map) -> Option<u32> {
+    Some(map.get_deletable_count())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut map = Map::from(input);
-    map.fall_and_get_deletable_counts();
+pub fn part_two(map: &Map) -> Option<u32> {
     Some(map.get_total_destruction_fall_count())
 }
 
-struct Map {
+pub struct Map {
     cubes: Vec<Cube>,
     dependencies: HashMap<u32, Vec<u32>>,
 }
 
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
+impl From<&Input> for Map {
+    fn from(value: &Input) -> Self {
         let mut cubes: Vec<Cube> = value.lines().map(|line| line.into()).collect::<Vec<_>>();
         cubes.sort_by(|a, b| a.z.start().cmp(b.z.start()));
+        let dependencies = Self::fall(&cubes);
         Self {
             cubes,
-            dependencies: HashMap::new(),
+            dependencies,
         }
     }
 }
 
 impl Map {
-    fn fall_and_get_deletable_counts(&mut self) -> u32 {
-        let mut deletable_cubes: HashSet<u32> =
-            HashSet::from_iter(self.cubes.iter().map(|cube| cube.id));
+    /// Lets the cubes fall and returns the cubes that each cube rests on.
+    fn fall(cubes: &[Cube]) -> HashMap<u32, Vec<u32>> {
         let mut filled_heights = vec![vec![(0, None); 10]; 10];
         let mut dependencies: HashMap<u32, Vec<u32>> = HashMap::new();
 
-        for cube in &self.cubes {
+        for cube in cubes {
             let mut highest_z = 0;
             let mut holding_cubes = HashSet::new();
             for x in cube.x.clone() {
@@ -55,9 +53,6 @@ impl Map {
                 }
             }
             dependencies.insert(cube.id, holding_cubes.iter().cloned().collect());
-            if holding_cubes.len() == 1 {
-                deletable_cubes.remove(&holding_cubes.iter().next().unwrap());
-            }
             for x in cube.x.clone() {
                 for y in cube.y.clone() {
                     filled_heights[x as usize][y as usize] =
@@ -66,7 +61,17 @@ impl Map {
             }
         }
 
-        self.dependencies = dependencies;
+        dependencies
+    }
+
+    fn get_deletable_count(&self) -> u32 {
+        let mut deletable_cubes: HashSet<u32> =
+            HashSet::from_iter(self.cubes.iter().map(|cube| cube.id));
+        for holding_cubes in self.dependencies.values() {
+            if holding_cubes.len() == 1 {
+                deletable_cubes.remove(&holding_cubes[0]);
+            }
+        }
         deletable_cubes.len() as u32
     }
 
@@ -162,13 +167,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&Map::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&Map::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(7));
     }
 }
@@ -1,17 +1,32 @@
-use std::collections::{HashSet};
-advent_of_code::solution!(23);
+use advent_of_code::template::Input;
+use std::collections::HashSet;
+advent_of_code::solution!(2023, 23, Trails::from);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let map = Map::from(input);
-    Some(map.get_longest_path())
+pub fn part_one(trails: &Trails) -> Option<u32> {
+    Some(trails.slippery.get_longest_path())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut map = Map::from(input);
-    map.set_steep_relevant(false);
+pub fn part_two(trails: &Trails) -> Option<u32> {
     // this is a really bad bruteforce approach for the second part
     // we could create a graph in which consecutive nodes are connected to edges
-    Some(map.get_longest_path())
+    Some(trails.dry.get_longest_path())
+}
+
+/// The map of the trails, once with slopes that can only be walked downhill for part one and once without for part two.
+pub struct Trails {
+    slippery: Map,
+    dry: Map,
+}
+
+impl From<&Input> for Trails {
+    fn from(value: &Input) -> Self {
+        let mut dry = Map::from(value);
+        dry.set_steep_relevant(false);
+        Self {
+            slippery: Map::from(value),
+            dry,
+        }
+    }
 }
 
 struct Map {
@@ -115,8 +130,8 @@ impl Map {
     }
 }
 
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
+impl From<&Input> for Map {
+    fn from(value: &Input) -> Self {
         let mut all_inputs: Vec<Vec<Tile>> = value
             .lines()
             .map(|line| line.chars().map(|c| c.into()).collect())
@@ -188,13 +203,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&Trails::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(94));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&Trails::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
         assert_eq!(result, Some(154));
     }
 }
@@ -1,22 +1,54 @@
+use advent_of_code::template::params::{self, Params};
+use advent_of_code::template::Input;
 use std::ops::RangeInclusive;
-advent_of_code::solution!(24);
+advent_of_code::solution!(2023, 24, parse, params: TestArea);
 
-#[cfg(test)]
-const TARGET_RANGE: RangeInclusive<f64> = 7.0..=27.0;
+/// The area in which the paths of the hailstones have to cross, on both the x and the y axis.
+pub struct TestArea {
+    min: f64,
+    max: f64,
+}
 
-#[cfg(not(test))]
-const TARGET_RANGE: RangeInclusive<f64> = 200000000000000.0..=400000000000000.0;
+impl TestArea {
+    fn range(&self) -> RangeInclusive<f64> {
+        self.min..=self.max
+    }
+}
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let lines = parse(input);
-    Some(count_xy_collisions_in_range(&lines))
+impl Params for TestArea {
+    fn example() -> Self {
+        Self {
+            min: 7.0,
+            max: 27.0,
+        }
+    }
+
+    fn real() -> Self {
+        Self {
+            min: 200000000000000.0,
+            max: 400000000000000.0,
+        }
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "min" => self.min = params::parse_value(value)?,
+            "max" => self.max = params::parse_value(value)?,
+            _ => return Err(params::unknown(name, &["min", "max"])),
+        }
+        Ok(())
+    }
+}
+
+pub fn part_one(lines: &Vec<Line>, params: &TestArea) -> Option<u32> {
+    Some(count_xy_collisions_in_range(lines, &params.range()))
 }
 
-pub fn part_two(_: &str) -> Option<u32> {
+pub fn part_two(_: &Vec<Line>, _: &TestArea) -> Option<u32> {
     None
 }
 
-fn parse(input: &str) -> Vec<Line> {
+fn parse(input: &Input) -> Vec<Line> {
     let mut lines = vec![];
     for line in input.lines() {
         let mut parts = line.split(" @ ");
@@ -29,12 +61,12 @@ fn parse(input: &str) -> Vec<Line> {
     lines
 }
 
-fn count_xy_collisions_in_range(lines: &Vec<Line>) -> u32 {
+fn count_xy_collisions_in_range(lines: &Vec<Line>, range: &RangeInclusive<f64>) -> u32 {
     let mut count = 0;
     for i in 0..lines.len() {
         for j in (i + 1)..lines.len() {
             if let Some((x, y)) = lines[i].get_xy_collision(&lines[j]) {
-                if TARGET_RANGE.contains(&x) && TARGET_RANGE.contains(&y) {
+                if range.contains(&x) && range.contains(&y) {
                     count += 1;
                 }
             }
@@ -44,7 +76,7 @@ fn count_xy_collisions_in_range(lines: &Vec<Line>) -> u32 {
     count
 }
 
-struct Line {
+pub struct Line {
     start: Vector,
     direction: Vector,
 }
@@ -102,13 +134,28 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &parse(&advent_of_code::template::read_file("examples", YEAR, DAY)),
+            &TestArea::example(),
+        );
         assert_eq!(result, Some(2));
     }
 
+    #[test]
+    fn test_part_one_real_params() {
+        let result = part_one(
+            &parse(&advent_of_code::template::read_file("examples", YEAR, DAY)),
+            &TestArea::real(),
+        );
+        assert_eq!(result, Some(0));
+    }
+
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &parse(&advent_of_code::template::read_file("examples", YEAR, DAY)),
+            &TestArea::example(),
+        );
         assert_eq!(result, None);
     }
 }
@@ -85,6 +85,9 @@ pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
+/// The number of stars that can be collected in a year, two for every day of [`all_days`].
+pub const MAX_STARS: usize = 25 * 2;
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub struct AllDays {
     current: u8,
@@ -114,6 +117,82 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days of advent, e.g. to select the days that a command runs.
+///
+/// It is parsed from a comma-separated list of days and ranges of days.
+///
+/// ```
+/// # use advent_of_code::{Day, DaySet};
+/// let days: DaySet = "1-3,17".parse().unwrap();
+/// assert_eq!(days.iter().map(Day::into_inner).collect::<Vec<_>>(), vec![1, 2, 3, 17]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// The set of every day of advent.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    pub fn contains(self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    /// Iterates the days of the set in ascending order.
+    pub fn iter(self) -> impl Iterator<Item = Day> {
+        all_days().filter(move |&day| self.contains(day))
+    }
+}
+
+impl Default for DaySet {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        Self(iter.into_iter().fold(0, |set, day| set | 1 << day.0))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = 0;
+
+        for item in s.split(',').map(str::trim) {
+            let (first, last) = item.split_once('-').unwrap_or((item, item));
+            let first: Day = first.trim().parse().map_err(|_| DaySetFromStrError)?;
+            let last: Day = last.trim().parse().map_err(|_| DaySetFromStrError)?;
+
+            if first > last {
+                return Err(DaySetFromStrError);
+            }
+
+            set |= (first.0..=last.0).fold(0, |set, day| set | 1 << day);
+        }
+
+        Ok(Self(set))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a comma-separated list of days and ranges of days, e.g. `1-10,17`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +213,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +246,22 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn day_set_from_str() {
+        let days: DaySet = "1-3, 17,2,25".parse().unwrap();
+        assert_eq!(
+            days.iter().collect::<Vec<_>>(),
+            vec![Day(1), Day(2), Day(3), Day(17), Day(25)]
+        );
+        assert!(days.contains(Day(17)) && !days.contains(Day(4)));
+        assert_eq!(DaySet::default().iter().count(), 25);
+
+        assert!("3-1".parse::<DaySet>().is_err());
+        assert!("1-26".parse::<DaySet>().is_err());
+        assert!("1,,2".parse::<DaySet>().is_err());
+        assert!("".parse::<DaySet>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,64 +1,161 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use std::process::ExitCode;
+
+use advent_of_code::template::{
+    commands::{all, download, examples, read, scaffold, solve, status, verify},
+    manifest,
+    runner::{InputSource, OutputFormat},
+    solution::Registry,
+    Error,
+};
 use args::{parse, AppArguments};
 
-mod args {
-    use std::process;
+#[cfg(feature = "in_process")]
+mod solutions;
 
-    use advent_of_code::Day;
+mod args {
+    use advent_of_code::{
+        template::{
+            commands::{all, scaffold},
+            runner::Statistic,
+            timings::Baseline,
+        },
+        Day, Year,
+    };
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
+            day: Day,
+        },
+        Examples {
+            year: Year,
             day: Day,
         },
         Scaffold {
+            year: Year,
             day: Day,
+            template: String,
+            answer_type: String,
         },
         Solve {
+            year: Year,
             day: Day,
-            release: bool,
-            time: bool,
+            /// Only `is_release`, `time`, `timeout` and `format` apply to a single day.
+            options: all::RunOptions,
+            /// The path passed via `--input`, `-` for stdin.
+            input: Option<String>,
+            example: bool,
+            /// Overrides of the parameters of the solution, as `name=value`.
+            params: Vec<String>,
             submit: Option<u8>,
+            watch: bool,
         },
         All {
-            release: bool,
-            time: bool,
+            /// [`None`] runs every year that has solutions.
+            year: Option<Year>,
+            options: all::RunOptions,
+            in_process: bool,
+            history: all::History,
+        },
+        Verify {
+            /// [`None`] verifies every year that has solutions.
+            year: Option<Year>,
+            options: all::RunOptions,
+            in_process: bool,
+        },
+        Status {
+            /// [`None`] shows every year that has scaffolded days.
+            year: Option<Year>,
+            update_readme: bool,
         },
     }
 
+    /// The slowdown in percent above which `--compare` reports a regression.
+    const DEFAULT_THRESHOLD: f64 = 10.0;
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        // options are parsed before the day, which is the first remaining free argument.
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+                year: parse_years(&mut args)?,
+                options: all::RunOptions {
+                    is_release: args.contains("--release"),
+                    time: parse_time(&mut args)?,
+                    timeout: args.opt_value_from_str("--timeout")?,
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    selection: parse_selection(&mut args)?,
+                },
+                in_process: args.contains("--in-process"),
+                history: all::History {
+                    save_as: args.opt_value_from_str("--baseline")?,
+                    compare: parse_compare(&mut args)?,
+                    threshold: args
+                        .opt_value_from_str("--threshold")?
+                        .unwrap_or(DEFAULT_THRESHOLD),
+                },
             },
             Some("download") => AppArguments::Download {
+                year: parse_year(&mut args)?,
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                year: parse_year(&mut args)?,
+                day: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                year: parse_year(&mut args)?,
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
+                year: parse_year(&mut args)?,
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
+                answer_type: args
+                    .opt_value_from_str("--answer-type")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_ANSWER_TYPE.into()),
                 day: args.free_from_str()?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
+                year: parse_year(&mut args)?,
+                options: all::RunOptions {
+                    is_release: args.contains("--release"),
+                    time: parse_time(&mut args)?,
+                    timeout: args.opt_value_from_str("--timeout")?,
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    ..Default::default()
+                },
+                input: args.opt_value_from_str("--input")?,
+                example: args.contains("--example"),
+                params: args.values_from_str("--param")?,
                 submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                watch: args.contains("--watch"),
+                day: args.free_from_str()?,
             },
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
+            Some("verify") => AppArguments::Verify {
+                year: parse_years(&mut args)?,
+                options: all::RunOptions {
+                    is_release: args.contains("--release"),
+                    timeout: args.opt_value_from_str("--timeout")?,
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    selection: parse_selection(&mut args)?,
+                    ..all::RunOptions::default()
+                },
+                in_process: args.contains("--in-process"),
+            },
+            Some("status") => AppArguments::Status {
+                year: parse_years(&mut args)?,
+                update_readme: args.contains("--update-readme"),
+            },
+            Some(x) => return Err(format!("unknown command: {x}").into()),
+            None => return Err("no command specified.".into()),
         };
 
         let remaining = args.finish();
@@ -68,25 +165,203 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parses `--year`, falling back to the year configured in `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env().ok_or_else(|| {
+                "no year specified. Pass --year <year> or set AOC_YEAR in .cargo/config.toml."
+                    .into()
+            }),
+        }
+    }
+
+    /// Parses `--all-years` as [`None`], otherwise like [`parse_year`].
+    fn parse_years(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Year>, Box<dyn std::error::Error>> {
+        if args.contains("--all-years") {
+            return Ok(None);
+        }
+        parse_year(args).map(Some)
+    }
+
+    /// Parses the options that select the days and parts to run.
+    fn parse_selection(
+        args: &mut pico_args::Arguments,
+    ) -> Result<all::Selection, pico_args::Error> {
+        Ok(all::Selection {
+            days: args.opt_value_from_str("--days")?.unwrap_or_default(),
+            part: args.opt_value_from_str("--part")?,
+            only_solved: args.contains("--only-solved"),
+            slowest: args.opt_value_from_str("--slowest")?,
+        })
+    }
+
+    /// Parses `--compare` as a flag comparing with the previous run, or `--compare=<baseline>`.
+    fn parse_compare(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Baseline>, pico_args::Error> {
+        if args.contains("--compare") {
+            return Ok(Some(Baseline::Previous));
+        }
+        Ok(args
+            .opt_value_from_str::<_, String>("--compare")?
+            .map(Baseline::Named))
+    }
+
+    /// Parses `--time` as a flag using the default statistic, or `--time=<statistic>`.
+    fn parse_time(args: &mut pico_args::Arguments) -> Result<Option<Statistic>, pico_args::Error> {
+        if args.contains("--time") {
+            return Ok(Some(Statistic::default()));
+        }
+        args.opt_value_from_str("--time")
+    }
 }
 
-fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::from(e.exit_code())
         }
-        Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
-                release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
-        },
-    };
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let args = parse().map_err(|e| Error::Usage(e.to_string()))?;
+
+    match args {
+        AppArguments::All {
+            year,
+            options,
+            in_process,
+            history,
+        } => {
+            if history.compare.is_some() && options.time.is_none() {
+                return Err(Error::Usage(
+                    "`--compare` needs timings, run it with `--time` or `cargo time`.".into(),
+                ));
+            }
+            check_in_process(&options, in_process)?;
+            let years = year.map_or_else(manifest::years, |year| vec![year]);
+            let registry = registry(in_process)?;
+            all::handle(&years, &options, registry.as_ref(), &history)
+        }
+        AppArguments::Verify {
+            year,
+            options,
+            in_process,
+        } => {
+            check_in_process(&options, in_process)?;
+            let years = year.map_or_else(manifest::years, |year| vec![year]);
+            let registry = registry(in_process)?;
+            verify::handle(&years, &options, registry.as_ref())
+        }
+        AppArguments::Status {
+            year,
+            update_readme,
+        } => {
+            let years = year.map_or_else(manifest::years, |year| vec![year]);
+            status::handle(&years, update_readme)
+        }
+        AppArguments::Download { year, day } => download::handle(year, day),
+        AppArguments::Read { year, day } => read::handle(year, day),
+        AppArguments::Examples { year, day } => examples::handle(year, day),
+        AppArguments::Scaffold {
+            year,
+            day,
+            template,
+            answer_type,
+        } => scaffold::handle(year, day, &template, &answer_type),
+        AppArguments::Solve {
+            year,
+            day,
+            options,
+            input,
+            example,
+            params,
+            submit,
+            watch,
+        } => {
+            let input = InputSource::select(input, example)?;
+            check_solve(&options, &input, &params, submit, watch)?;
+            if watch {
+                solve::watch(year, day, &options)
+            } else {
+                solve::handle(year, day, &options, &input, &params, submit)
+            }
+        }
+    }
+}
+
+/// Answers are only submitted for the puzzle input.
+/// Watching reruns the tests and the puzzle input on every change, which must neither submit answers nor mix records with the reported changes.
+fn check_solve(
+    options: &all::RunOptions,
+    input: &InputSource,
+    params: &[String],
+    submit: Option<u8>,
+    watch: bool,
+) -> Result<(), Error> {
+    if submit.is_some() && *input != InputSource::Puzzle {
+        return Err(Error::Usage(
+            "answers can only be submitted for the puzzle input, `--submit` cannot be combined with `--input` or `--example`.".into(),
+        ));
+    }
+    if !watch {
+        return Ok(());
+    }
+    if submit.is_some() {
+        return Err(Error::Usage(
+            "`--watch` cannot be combined with `--submit`.".into(),
+        ));
+    }
+    if options.format != OutputFormat::Text {
+        return Err(Error::Usage(
+            "`--watch` prints text only, it cannot be combined with `--format`.".into(),
+        ));
+    }
+    if *input != InputSource::Puzzle || !params.is_empty() {
+        return Err(Error::Usage(
+            "`--watch` runs the examples as tests, it cannot be combined with `--input`, `--example` or `--param`.".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// The solutions that are compiled into the CLI, if the days should run in-process.
+#[cfg(feature = "in_process")]
+fn registry(in_process: bool) -> Result<Option<Registry>, Error> {
+    Ok(in_process.then(solutions::registry))
+}
+
+/// Without the `in_process` feature, the solutions are not compiled into the CLI.
+#[cfg(not(feature = "in_process"))]
+fn registry(in_process: bool) -> Result<Option<Registry>, Error> {
+    if in_process {
+        return Err(Error::Usage(
+            "`--in-process` needs the solutions compiled into the CLI, build it with `--features in_process`, e.g. `cargo run --release --features in_process -- all --in-process`.".into(),
+        ));
+    }
+    Ok(None)
+}
+
+/// Solutions that run in-process share the output of the CLI, so they cannot run in parallel.
+/// They share the process as well, so a part that exceeds its time budget cannot be aborted.
+fn check_in_process(options: &all::RunOptions, in_process: bool) -> Result<(), Error> {
+    if in_process && options.jobs > 1 {
+        return Err(Error::Usage(
+            "`--jobs` runs the solution binaries in parallel, it cannot be combined with `--in-process`.".into(),
+        ));
+    }
+    if in_process && options.timeout.is_some() {
+        return Err(Error::Usage(
+            "`--timeout` aborts the solution binaries, it cannot be combined with `--in-process`."
+                .into(),
+        ));
+    }
+    Ok(())
 }
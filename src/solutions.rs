@@ -0,0 +1,54 @@
+//! Solutions compiled into the CLI, so that `cargo all --in-process` can run them without spawning `cargo run` per day.
+//! `cargo scaffold` registers new days here. The module is only compiled with the `in_process` feature,
+//! so that a day that does not build yet does not break the other commands.
+
+use advent_of_code::template::solution::Registry;
+
+macro_rules! solutions {
+    ($($module:ident => $path:literal),* $(,)?) => {
+        // the tests of each day already run as part of its own binary.
+        $(
+            #[cfg(not(test))]
+            #[path = $path]
+            mod $module;
+        )*
+
+        /// A registry containing every registered day.
+        pub fn registry() -> Registry {
+            #[allow(unused_mut)]
+            let mut registry = Registry::new();
+            $(
+                #[cfg(not(test))]
+                registry.register($module::solution());
+            )*
+            registry
+        }
+    };
+}
+
+solutions! {
+    y2023_day01 => "bin/2023-01.rs",
+    y2023_day02 => "bin/2023-02.rs",
+    y2023_day03 => "bin/2023-03.rs",
+    y2023_day04 => "bin/2023-04.rs",
+    y2023_day05 => "bin/2023-05.rs",
+    y2023_day06 => "bin/2023-06.rs",
+    y2023_day07 => "bin/2023-07.rs",
+    y2023_day08 => "bin/2023-08.rs",
+    y2023_day09 => "bin/2023-09.rs",
+    y2023_day10 => "bin/2023-10.rs",
+    y2023_day11 => "bin/2023-11.rs",
+    y2023_day12 => "bin/2023-12.rs",
+    y2023_day13 => "bin/2023-13.rs",
+    y2023_day14 => "bin/2023-14.rs",
+    y2023_day15 => "bin/2023-15.rs",
+    y2023_day16 => "bin/2023-16.rs",
+    y2023_day17 => "bin/2023-17.rs",
+    y2023_day18 => "bin/2023-18.rs",
+    y2023_day19 => "bin/2023-19.rs",
+    y2023_day20 => "bin/2023-20.rs",
+    y2023_day21 => "bin/2023-21.rs",
+    y2023_day22 => "bin/2023-22.rs",
+    y2023_day23 => "bin/2023-23.rs",
+    y2023_day24 => "bin/2023-24.rs",
+}
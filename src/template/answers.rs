@@ -0,0 +1,113 @@
+/// Module that stores accepted answers in `data/<year>/answers/<day>.txt` so that solutions can be verified against them.
+/// Each line of a file holds the answer of one part, e.g. `1: 42`.
+use std::{collections::BTreeMap, fmt::Display, fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{data_dir, get_data_path};
+use crate::{Day, Year};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verification {
+    /// There is no accepted answer for this part yet.
+    #[default]
+    Unknown,
+    Correct,
+    Incorrect,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verification::Unknown => "unknown",
+            Verification::Correct => "correct",
+            Verification::Incorrect => "incorrect",
+        })
+    }
+}
+
+#[must_use]
+pub fn get_answers_path(year: Year, day: Day) -> String {
+    get_data_path("answers", year, day)
+}
+
+/// Reads the accepted answers for a day, keyed by part. A missing file yields no answers.
+#[must_use]
+pub fn read(year: Year, day: Day) -> BTreeMap<u8, String> {
+    fs::read_to_string(get_answers_path(year, day))
+        .map(|content| parse(&content))
+        .unwrap_or_default()
+}
+
+/// Returns the accepted answer for a part, if there is one.
+#[must_use]
+pub fn expected(year: Year, day: Day, part: u8) -> Option<String> {
+    read(year, day).remove(&part)
+}
+
+/// Stores an accepted answer, replacing a previously stored answer for the same part.
+pub fn record(year: Year, day: Day, part: u8, answer: &str) -> Result<(), io::Error> {
+    let mut answers = read(year, day);
+    answers.insert(part, answer.to_string());
+    fs::create_dir_all(data_dir::path(format!("{year}/answers")))?;
+    fs::write(get_answers_path(year, day), format_answers(&answers))
+}
+
+/// Compares an answer against the accepted answer.
+#[must_use]
+pub fn verify(expected: Option<&str>, answer: Option<&str>) -> Verification {
+    match expected {
+        None => Verification::Unknown,
+        Some(expected) if answer == Some(expected) => Verification::Correct,
+        Some(_) => Verification::Incorrect,
+    }
+}
+
+fn parse(content: &str) -> BTreeMap<u8, String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(':')?;
+            let answer = answer.trim();
+            if answer.is_empty() {
+                return None;
+            }
+            Some((part.trim().parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+fn format_answers(answers: &BTreeMap<u8, String>) -> String {
+    answers
+        .iter()
+        .map(|(part, answer)| format!("{part}: {answer}\n"))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_answers, parse, verify, Verification};
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse("1: 42\n2:  foo: bar \n3:\ninvalid\n");
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[&1], "42");
+        assert_eq!(answers[&2], "foo: bar");
+    }
+
+    #[test]
+    fn formats_answers() {
+        let answers = parse("2: 7\n1: 42\n");
+        assert_eq!(format_answers(&answers), "1: 42\n2: 7\n");
+    }
+
+    #[test]
+    fn verifies_answers() {
+        assert_eq!(verify(None, Some("42")), Verification::Unknown);
+        assert_eq!(verify(Some("42"), Some("42")), Verification::Correct);
+        assert_eq!(verify(Some("42"), Some("41")), Verification::Incorrect);
+        assert_eq!(verify(Some("42"), None), Verification::Incorrect);
+    }
+}
@@ -0,0 +1,83 @@
+/// Access to the Advent of Code website, either via the "aoc-cli" command-line or the built-in HTTP client.
+/// The backend is selected with the `AOC_BACKEND` environment variable (`aoc-cli` or `http`).
+use std::env;
+
+use crate::template::{
+    answers,
+    aoc_cli::{self, AocCli},
+    aoc_http::HttpClient,
+    data_dir, get_data_path, manifest,
+    submissions::{self, Verdict},
+    Error,
+};
+use crate::{Day, Year};
+
+pub trait AocBackend {
+    /// Downloads the input and the puzzle description of a day to the `data` directory.
+    fn download(&self, year: Year, day: Day) -> Result<(), Error>;
+
+    /// Prints the puzzle description of a day.
+    fn read(&self, year: Year, day: Day) -> Result<(), Error>;
+
+    /// Submits an answer for one part and returns the text of the response.
+    fn submit(&self, year: Year, day: Day, part: u8, answer: &str) -> Result<String, Error>;
+}
+
+/// Returns the backend selected by `AOC_BACKEND`, defaulting to aoc-cli.
+pub fn backend() -> Result<Box<dyn AocBackend>, Error> {
+    match env::var("AOC_BACKEND").as_deref() {
+        Err(_) | Ok("" | "aoc-cli") => {
+            aoc_cli::check()?;
+            Ok(Box::new(AocCli))
+        }
+        Ok("http") => Ok(Box::new(HttpClient::from_env()?)),
+        Ok(name) => Err(Error::UnknownBackend(name.into())),
+    }
+}
+
+/// Submits an answer and records the verdict in the submission log.
+/// Accepted answers are also stored for verification, and the part is marked as solved in the manifest.
+pub fn submit(
+    backend: &dyn AocBackend,
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, Error> {
+    let response = backend.submit(year, day, part, answer)?;
+    let verdict = Verdict::from_response(&response);
+
+    match submissions::record(year, day, part, answer, verdict.clone()) {
+        Ok(()) => println!(
+            "🎄 Recorded verdict \"{verdict}\" in \"{}\".",
+            submissions::get_submissions_path(year, day)
+        ),
+        Err(e) => eprintln!("Failed to record submission: {e}"),
+    }
+
+    if verdict == Verdict::Correct {
+        match answers::record(year, day, part, answer) {
+            Ok(()) => println!(
+                "🎄 Recorded accepted answer in \"{}\".",
+                answers::get_answers_path(year, day)
+            ),
+            Err(e) => eprintln!("Failed to record accepted answer: {e}"),
+        }
+
+        if let Err(e) = manifest::mark_solved(year, day, part) {
+            eprintln!("Failed to mark part {part} as solved: {e}");
+        }
+    }
+
+    Ok(verdict)
+}
+
+#[must_use]
+pub fn get_input_path(year: Year, day: Day) -> String {
+    get_data_path("inputs", year, day)
+}
+
+#[must_use]
+pub fn get_puzzle_path(year: Year, day: Day) -> String {
+    data_dir::path(format!("{year}/puzzles/{day}.md"))
+}
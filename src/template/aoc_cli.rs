@@ -1,42 +1,46 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
-    fmt::Display,
+    io::{stderr, stdout, Write},
     process::{Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::template::{
+    aoc_backend::{get_input_path, get_puzzle_path, AocBackend},
+    create_parent_dir, Error,
+};
+use crate::{Day, Year};
 
-#[derive(Debug)]
-pub enum AocCommandError {
-    CommandNotFound,
-    CommandNotCallable,
-    BadExitStatus(Output),
-    IoError,
-}
+/// Backend that shells out to the "aoc-cli" command-line.
+pub struct AocCli;
+
+impl AocBackend for AocCli {
+    fn download(&self, year: Year, day: Day) -> Result<(), Error> {
+        download(year, day)?;
+        Ok(())
+    }
 
-impl Display for AocCommandError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-            AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-            AocCommandError::BadExitStatus(_) => {
-                write!(f, "aoc-cli exited with a non-zero status.")
-            }
-            AocCommandError::IoError => write!(f, "could not write output files to file system."),
-        }
+    fn read(&self, year: Year, day: Day) -> Result<(), Error> {
+        read(year, day)?;
+        Ok(())
+    }
+
+    fn submit(&self, year: Year, day: Day, part: u8, answer: &str) -> Result<String, Error> {
+        let output = submit(year, day, part, answer)?;
+        Ok(get_output_text(&output))
     }
 }
 
-pub fn check() -> Result<(), AocCommandError> {
+pub fn check() -> Result<(), Error> {
     Command::new("aoc")
         .arg("-V")
         .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
+        .map_err(|_| Error::CliNotFound)?;
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(year: Year, day: Day) -> Result<Output, Error> {
+    let puzzle_path = get_puzzle_path(year, day);
+    create_parent_dir(&puzzle_path)?;
 
     let args = build_args(
         "read",
@@ -45,15 +49,18 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
+        year,
         day,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(year: Year, day: Day) -> Result<Output, Error> {
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+    create_parent_dir(&input_path)?;
+    create_parent_dir(&puzzle_path)?;
 
     let args = build_args(
         "download",
@@ -64,64 +71,84 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
+        year,
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+    call_aoc_cli(&args)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<Output, Error> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], year, day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+    call_aoc_cli_captured(&args)
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], year: Year, day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        year.to_string(),
+        "--day".into(),
+        day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+fn call_aoc_cli(args: &[String]) -> Result<Output, Error> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
+        .map_err(|source| Error::Spawn {
+            command: "aoc".into(),
+            source,
+        })?;
 
     if output.status.success() {
         Ok(output)
     } else {
-        Err(AocCommandError::BadExitStatus(output))
+        Err(Error::Command {
+            command: format!("aoc {}", args.join(" ")),
+            status: output.status,
+        })
     }
 }
+
+/// Calls aoc-cli with captured output, which is forwarded once the command has finished.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, Error> {
+    let output = Command::new("aoc")
+        .args(args)
+        .output()
+        .map_err(|source| Error::Spawn {
+            command: "aoc".into(),
+            source,
+        })?;
+
+    let _ = stdout().write_all(&output.stdout);
+    let _ = stderr().write_all(&output.stderr);
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(Error::Command {
+            command: format!("aoc {}", args.join(" ")),
+            status: output.status,
+        })
+    }
+}
+
+fn get_output_text(output: &Output) -> String {
+    format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
@@ -0,0 +1,276 @@
+/// Built-in client for the Advent of Code website, used instead of aoc-cli when `AOC_BACKEND=http`.
+/// The session cookie is read from `AOC_SESSION` or from the `.adventofcode.session` file in the home directory, like aoc-cli does.
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use crate::template::{
+    aoc_backend::{get_input_path, get_puzzle_path, AocBackend},
+    create_parent_dir, markdown, Error,
+};
+use crate::{Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (advent-of-code-rust template)"
+);
+
+pub struct HttpClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpClient {
+    /// Creates a client for the website at `base_url`, e.g. a local server in tests.
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                // the website redirects to the login page if the session is invalid, which should be reported as such.
+                .redirects(0)
+                .build(),
+        }
+    }
+
+    /// Creates a client from the session cookie and, optionally, `AOC_URL`.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = read_session().ok_or(Error::MissingSession)?;
+        let base_url = env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn fetch_input(&self, year: Year, day: Day) -> Result<String, Error> {
+        self.get(&format!("{}/input", self.day_url(year, day)))
+    }
+
+    /// Fetches the puzzle description, converted to markdown.
+    pub fn fetch_puzzle(&self, year: Year, day: Day) -> Result<String, Error> {
+        self.get(&self.day_url(year, day))
+            .map(|html| markdown::from_html(&html))
+    }
+
+    /// Posts an answer and returns the response, converted to markdown.
+    pub fn post_answer(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, Error> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let request = self.agent.post(&url).set("Cookie", &self.cookie());
+        let response = request.send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        read_response(response, &url).map(|html| markdown::from_html(&html))
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call();
+        read_response(response, url)
+    }
+
+    fn day_url(&self, year: Year, day: Day) -> String {
+        format!("{}/{year}/day/{}", self.base_url, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+impl AocBackend for HttpClient {
+    fn download(&self, year: Year, day: Day) -> Result<(), Error> {
+        let input = self.fetch_input(year, day)?;
+        let puzzle = self.fetch_puzzle(year, day)?;
+
+        write_file(&get_input_path(year, day), &input)?;
+        write_file(&get_puzzle_path(year, day), &puzzle)
+    }
+
+    fn read(&self, year: Year, day: Day) -> Result<(), Error> {
+        let puzzle = self.fetch_puzzle(year, day)?;
+
+        write_file(&get_puzzle_path(year, day), &puzzle)?;
+        println!("{puzzle}");
+        Ok(())
+    }
+
+    fn submit(&self, year: Year, day: Day, part: u8, answer: &str) -> Result<String, Error> {
+        let response = self.post_answer(year, day, part, answer)?;
+        println!("{response}");
+        Ok(response)
+    }
+}
+
+fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+    url: &str,
+) -> Result<String, Error> {
+    let response = match response {
+        Ok(response) if response.status() == 200 => response,
+        // redirects are not followed, see `HttpClient::new`.
+        Ok(response) | Err(ureq::Error::Status(_, response)) => {
+            return Err(Error::Http {
+                status: response.status(),
+                url: url.into(),
+            })
+        }
+        Err(e) => return Err(Error::Network(e.to_string())),
+    };
+
+    response
+        .into_string()
+        .map_err(|e| Error::Network(e.to_string()))
+}
+
+fn write_file(path: &str, content: &str) -> Result<(), Error> {
+    create_parent_dir(path)?;
+    fs::write(path, content).map_err(Error::io(path))
+}
+
+fn read_session() -> Option<String> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(get_session_path()?).ok()?,
+    };
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+fn get_session_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".adventofcode.session"))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::HttpClient;
+    use crate::template::Error;
+    use crate::{day, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves one canned response per request on a local port and returns the requests it received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                            content_length = length.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+
+                    let mut body_bytes = vec![0; content_length];
+                    reader.read_exact(&mut body_bytes).unwrap();
+                    request.push_str(&String::from_utf8(body_bytes).unwrap());
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (url, server) = serve(vec![(200, "1abc2\n")]);
+        let client = HttpClient::new(&url, "secret");
+
+        assert_eq!(client.fetch_input(year!(2023), day!(1)).unwrap(), "1abc2\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (url, server) = serve(vec![(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 7: Camel Cards ---</h2><p>Play <em>Camel Cards</em>.</p></article></main>",
+        )]);
+        let client = HttpClient::new(&url, "secret");
+
+        assert_eq!(
+            client.fetch_puzzle(year!(2023), day!(7)).unwrap(),
+            "## --- Day 7: Camel Cards ---\n\nPlay *Camel Cards*.\n"
+        );
+        assert!(server.join().unwrap()[0].starts_with("GET /2023/day/7 HTTP/1.1"));
+    }
+
+    #[test]
+    fn posts_answer() {
+        let (url, server) = serve(vec![(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = HttpClient::new(&url, "secret");
+
+        let response = client
+            .post_answer(year!(2023), day!(12), 2, "525152")
+            .unwrap();
+        assert!(response.starts_with("That's the right answer!"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/12/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=525152"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, server) = serve(vec![(400, "Puzzle inputs differ by user."), (302, "")]);
+        let client = HttpClient::new(&url, "expired");
+
+        assert!(matches!(
+            client.fetch_input(year!(2023), day!(1)),
+            Err(Error::Http { status: 400, .. })
+        ));
+        assert!(matches!(
+            client.fetch_input(year!(2023), day!(1)),
+            Err(Error::Http { status: 302, .. })
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_unreachable_server() {
+        let client = HttpClient::new("http://127.0.0.1:1", "secret");
+        assert!(matches!(
+            client.fetch_input(year!(2023), day!(1)),
+            Err(Error::Network(_))
+        ));
+    }
+}
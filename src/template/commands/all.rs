@@ -1,189 +1,755 @@
-use std::io;
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    manifest,
+    readme_benchmarks::{self, PartTiming, Timings},
+    runner::{OutputFormat, PartResult, Statistic, TimeBudget},
+    solution::Registry,
+    timings::{self, Baseline, Delta},
+    Error, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, Day, DaySet, Year};
+
+/// How the days are run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    pub is_release: bool,
+    /// Benches the parts and reports this statistic.
+    pub time: Option<Statistic>,
+    /// Aborts parts that exceed this budget, except for solutions that run in-process.
+    pub timeout: Option<TimeBudget>,
+    pub format: OutputFormat,
+    /// The number of solution binaries that run at the same time.
+    /// Timed runs and solutions that run in-process are always run one day after the other.
+    pub jobs: usize,
+    pub selection: Selection,
+}
+
+/// Which days and parts of a year are run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Selection {
+    pub days: DaySet,
+    /// Runs only this part of every day, besides the parse step.
+    pub part: Option<u8>,
+    /// Skips days whose selected parts are not solved yet according to the manifest.
+    pub only_solved: bool,
+    /// Runs only this many of the selected days, the ones that took longest in their last timed run.
+    pub slowest: Option<usize>,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+impl Selection {
+    /// The selected days of a year, in ascending order.
+    pub fn days(&self, year: Year) -> Result<Vec<Day>, Error> {
+        let manifest = manifest::read(year)?;
+        let parts = self.part.map_or(vec![1, 2], |part| vec![part]);
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+        let mut days: Vec<Day> = self
+            .days
+            .iter()
+            .filter(|day| {
+                !self.only_solved
+                    || manifest
+                        .get(day)
+                        .is_some_and(|status| parts.iter().all(|&part| !status.is_open(part)))
+            })
+            .collect();
+
+        if let Some(slowest) = self.slowest {
+            let last_timings = timings::read(year);
+            let total = |day: &Day| {
+                let timing = last_timings.get(day)?;
+                parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        1 => timing.part_1,
+                        2 => timing.part_2,
+                        _ => None,
+                    })
+                    .reduce(|a, b| a + b)
+            };
+
+            // days that were never timed cannot be among the slowest.
+            days.retain(|day| total(day).is_some());
+            days.sort_by_key(|day| Reverse(total(day)));
+            days.truncate(slowest);
+            days.sort_unstable();
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        Ok(days)
+    }
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// What to do with the benchmark history after a timed run.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    /// Saves the run as baseline under this name.
+    pub save_as: Option<String>,
+    /// Compares the run with a previous run.
+    pub compare: Option<Baseline>,
+    /// The slowdown in percent above which a part counts as regression.
+    pub threshold: f64,
+}
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+/// Runs every day of the given years. When a `registry` is given, the days are run in-process instead of spawning their binaries.
+/// Timed runs are added to the benchmark history.
+pub fn handle(
+    years: &[Year],
+    options: &RunOptions,
+    registry: Option<&Registry>,
+    history: &History,
+) -> Result<(), Error> {
+    let RunOptions {
+        is_release,
+        time,
+        format,
+        ..
+    } = *options;
+
+    if time.is_some() && options.jobs > 1 {
+        print_info(
+            format,
+            "Timed runs do not run days in parallel, ignoring `--jobs`.",
+        );
+    }
+
+    let mut total_millis = 0_f64;
+    let mut failed_days = 0;
+    let mut regressions = 0;
+    let timed_run = timings::Run::start(history.save_as.clone());
+
+    for (i, &year) in years.iter().enumerate() {
+        if years.len() > 1 {
+            if i > 0 {
+                print_info(format, "");
+            }
+            print_info(format, &format!("{ANSI_BOLD}== {year} =={ANSI_RESET}\n"));
         }
-    });
 
-    if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+        let run = run_days(year, options, registry)?;
+        failed_days += run.failed_days;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        let timings: Vec<Timings> = run
+            .results
+            .iter()
+            .map(|(day, results)| child_commands::collect_timings(results, *day))
+            .collect();
+
+        if time.is_some() {
+            let year_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+            total_millis += year_millis;
+
+            print_info(
+                format,
+                &format!(
+                    "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{year_millis:.2}ms{ANSI_RESET}"
+                ),
+            );
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+            if let Some(baseline) = &history.compare {
+                regressions += compare(year, baseline, history.threshold, &run.results, format);
+            }
+
+            if let Err(e) = timings::record(year, &timed_run, &run.results) {
+                eprintln!("Failed to record timings for {year}: {e}");
+            }
+
+            if is_release {
+                let timings = with_previous_timings(year, &run, options.selection.part, timings);
+                let table_millis =
+                    timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+
+                match readme_benchmarks::update(year, timings, table_millis) {
+                    Ok(()) => print_info(format, "Successfully updated README with benchmarks."),
+                    Err(e) => {
+                        eprintln!("Failed to update readme with benchmarks for {year}: {e}");
+                    }
                 }
             }
         }
     }
+
+    if time.is_some() && years.len() > 1 {
+        print_info(
+            format,
+            &format!(
+                "\n{ANSI_BOLD}Total of all years:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            ),
+        );
+    }
+
+    match (failed_days, regressions) {
+        (0, 0) => Ok(()),
+        (0, n) => Err(Error::Regressions(n)),
+        (n, _) => Err(Error::DaysFailed(n)),
+    }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+/// Completes the timings of a run that skipped days or parts with their latest timings in the benchmark history,
+/// so that the benchmark table keeps the rows of everything that was not run.
+/// `part` is the only part that was run of the selected days, if any.
+fn with_previous_timings(
+    year: Year,
+    run: &Run,
+    part: Option<u8>,
+    timings: Vec<Timings>,
+) -> Vec<Timings> {
+    let history = timings::read_history(year);
+    let latest = timings::latest(&history);
+    let manifest = manifest::read(year).unwrap_or_default();
+    let mut timings: BTreeMap<Day, Timings> = timings
+        .into_iter()
+        .map(|timing| (timing.day, timing))
+        .collect();
+
+    for day in all_days() {
+        let parts: Vec<u8> = match (run.days.contains(&day), part) {
+            (false, _) if latest.keys().any(|(d, _)| *d == day) => vec![0, 1, 2],
+            (true, Some(part)) if timings.contains_key(&day) => {
+                [1, 2].into_iter().filter(|&p| p != part).collect()
+            }
+            _ => continue,
+        };
+
+        let timing = timings.entry(day).or_insert_with(|| Timings::new(day));
+        for part in parts {
+            match latest.get(&(day, part)) {
+                Some(entry) => timing.add(part, PartTiming::Solved(entry.duration), entry.samples),
+                None if manifest
+                    .get(&day)
+                    .is_some_and(|status| status.is_open(part)) =>
+                {
+                    timing.add(part, PartTiming::Open, None);
+                }
+                None => {}
+            }
+        }
+    }
+
+    timings.into_values().collect()
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+/// Prints the change of every part versus the baseline and returns the number of regressions.
+fn compare(
+    year: Year,
+    baseline: &Baseline,
+    threshold: f64,
+    results: &[(Day, Vec<PartResult>)],
+    format: OutputFormat,
+) -> usize {
+    let Some((run, deltas)) = timings::compare(&timings::read_history(year), baseline, results)
+    else {
+        eprintln!(
+            "No baseline to compare with in \"{}\".",
+            timings::get_history_path(year)
+        );
+        return 0;
+    };
+
+    print_info(
+        format,
+        &format!("\n{ANSI_BOLD}Compared with {run}:{ANSI_RESET}"),
+    );
+
+    let mut regressions = 0;
+    for delta in &deltas {
+        let is_regression = delta.is_regression(threshold);
+        regressions += usize::from(is_regression);
+        print_info(format, &format_delta(delta, is_regression));
     }
+
+    print_info(
+        format,
+        &format!(
+            "{} parts compared, {regressions} slower by more than {threshold}%.",
+            deltas.len()
+        ),
+    );
+
+    regressions
+}
+
+fn format_delta(delta: &Delta, is_regression: bool) -> String {
+    let part = match delta.part {
+        0 => "parse".to_string(),
+        part => format!("part {part}"),
+    };
+    let marker = if is_regression { " ✘ slower" } else { "" };
+
+    format!(
+        "Day {} {part}: {:.1?} → {:.1?} ({:+.1}%){marker}",
+        delta.day,
+        delta.before,
+        delta.after,
+        delta.change_percent()
+    )
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// The outcome of running every day of a year.
+pub struct Run {
+    /// The days that were selected to run.
+    pub days: Vec<Day>,
+    /// The results of all days that produced any.
+    pub results: Vec<(Day, Vec<PartResult>)>,
+    /// The number of days whose solution failed. Their errors have been printed already.
+    pub failed_days: usize,
+}
+
+/// Runs every day of a year and prints its output.
+/// A failing solution does not stop the remaining days, only errors that affect every day are returned.
+pub fn run_days(
+    year: Year,
+    options: &RunOptions,
+    registry: Option<&Registry>,
+) -> Result<Run, Error> {
+    let format = options.format;
+    let days = options.selection.days(year)?;
+    let mut run = Run {
+        days: days.clone(),
+        results: vec![],
+        failed_days: 0,
+    };
+
+    let mut report = |day: Day, results: Result<Vec<PartResult>, Error>| {
+        match results {
+            Ok(results) if results.is_empty() => print_info(format, "Not solved."),
+            Ok(results) => run.results.push((day, results)),
+            Err(e @ Error::Spawn { .. }) => return Err(e),
+            Err(e) => {
+                eprintln!("Error: {}", e.for_day(year, day));
+                run.failed_days += 1;
+            }
+        }
+        Ok(())
+    };
+
+    match registry {
+        Some(registry) => {
+            for &day in &days {
+                print_header(format, day, &days);
+                report(day, in_process::run_solution(year, day, options, registry))?;
+            }
+        }
+        None if options.jobs > 1 && options.time.is_none() => {
+            run_in_parallel(year, &days, options, |day, results, output| {
+                print_header(format, day, &days);
+                output.print(format);
+                report(day, results)
+            })?;
+        }
+        None => {
+            for &day in &days {
+                print_header(format, day, &days);
+                let mut output = child_commands::Output::Direct;
+                report(
+                    day,
+                    child_commands::run_solution(year, day, options, None, &mut output),
+                )?;
+            }
+        }
+    }
+
+    Ok(run)
+}
+
+/// Runs the solution binary of a single day via `cargo run` and prints its output.
+pub fn run_day(year: Year, day: Day, options: &RunOptions) -> Result<Vec<PartResult>, Error> {
+    let mut output = child_commands::Output::Direct;
+    child_commands::run_solution(year, day, options, None, &mut output)
+}
+
+/// Runs the solution binaries of several days at the same time.
+/// Their output is kept and passed to `report` one day after the other, in the order of the days.
+///
+/// `cargo run` holds a lock on the build directory while the binary runs, so the binaries are built up front and then invoked directly.
+fn run_in_parallel(
+    year: Year,
+    days: &[Day],
+    options: &RunOptions,
+    mut report: impl FnMut(
+        Day,
+        Result<Vec<PartResult>, Error>,
+        child_commands::Output,
+    ) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let target_dir = child_commands::build(options.is_release)?;
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let sender = sender.clone();
+            let (next, target_dir) = (&next, &target_dir);
+
+            scope.spawn(move || {
+                let mut i = next.fetch_add(1, Ordering::Relaxed);
+
+                while let Some(&day) = days.get(i) {
+                    let mut output = child_commands::Output::Buffered(vec![]);
+                    let results = child_commands::run_solution(
+                        year,
+                        day,
+                        options,
+                        Some(target_dir),
+                        &mut output,
+                    );
+
+                    // the receiver is gone if reporting a day failed, the remaining days are not needed then.
+                    if sender.send((i, results, output)).is_err() {
+                        break;
+                    }
+
+                    i = next.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+
+        // the receiver ends once every worker dropped its sender.
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut reported = 0;
+
+        for (i, results, output) in receiver {
+            finished.insert(i, (results, output));
+
+            while let Some((results, output)) = finished.remove(&reported) {
+                report(days[reported], results, output)?;
+                reported += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+fn print_header(format: OutputFormat, day: Day, days: &[Day]) {
+    if format == OutputFormat::Text {
+        if days.first() != Some(&day) {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    }
+}
+
+/// Prints informational output. When writing JSON, stdout is reserved for the result records.
+fn print_info(format: OutputFormat, message: &str) {
+    match format {
+        OutputFormat::Text => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
+    }
+}
+
+/// Solutions that are compiled into the CLI can be invoked directly.
+/// Timing and output are handled by the same runner that the solution binaries use.
+mod in_process {
+    use crate::template::{
+        runner::{PartResult, RunSettings},
+        solution::Registry,
+        try_read_file, Error, Input,
+    };
+    use crate::{Day, Year};
+    use std::panic::{self, AssertUnwindSafe};
+
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        options: &super::RunOptions,
+        registry: &Registry,
+    ) -> Result<Vec<PartResult>, Error> {
+        let Some(solution) = registry.get(year, day) else {
+            return Ok(vec![]);
+        };
+
+        let input = try_read_file("inputs", year, day)?;
+        let settings = RunSettings {
+            time: options.time,
+            format: options.format,
+            timeout: options.timeout,
+            part: options.selection.part,
+            ..RunSettings::default()
+        };
+
+        // a panicking solution should not abort the remaining days, mirroring a failed child command.
+        // solutions are not run again after a panic, so observing broken state is not a concern.
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            solution.run(Input::new(&input), &settings)
+        }))
+        .map_err(|_| Error::Panicked)?
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the JSON records they emit.
 mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+    use crate::template::{
+        manifest,
+        readme_benchmarks::PartTiming,
+        runner::{self, OutputFormat, PartResult, PartStatus},
+        Error,
+    };
+    use crate::{Day, Year};
+    use serde::Deserialize;
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
+        env::consts::EXE_SUFFIX,
+        io::{self, BufRead, BufReader},
+        path::{Path, PathBuf},
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Where the output of a solution binary goes.
+    pub enum Output {
+        /// The output is printed as it arrives.
+        Direct,
+        /// The output is kept until [`Output::print`] is called.
+        Buffered(Vec<Line>),
+    }
+
+    pub enum Line {
+        Record(PartResult, String),
+        Text(String),
+        Error(String),
+    }
+
+    impl Output {
+        fn push(&mut self, line: Line, format: OutputFormat) {
+            match self {
+                Output::Direct => print_line(&line, format),
+                Output::Buffered(lines) => lines.push(line),
+            }
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        /// Prints the kept output.
+        pub fn print(self, format: OutputFormat) {
+            if let Output::Buffered(lines) = self {
+                lines.iter().for_each(|line| print_line(line, format));
+            }
+        }
+    }
+
+    fn print_line(line: &Line, format: OutputFormat) {
+        match (line, format) {
+            (Line::Record(result, _), OutputFormat::Text) => runner::print_part_result(result),
+            (Line::Record(_, line), OutputFormat::Json)
+            | (Line::Text(line), OutputFormat::Text) => {
+                println!("{line}");
+            }
+            (Line::Text(line), OutputFormat::Json) | (Line::Error(line), _) => eprintln!("{line}"),
+        }
+    }
+
+    /// Builds all solution binaries and returns the target directory that they were written to, see [`run_solution`].
+    pub fn build(is_release: bool) -> Result<PathBuf, Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let command = format!("cargo {}", args.join(" "));
+        let status = Command::new("cargo")
+            .args(&args)
+            .status()
+            .map_err(|source| Error::Spawn {
+                command: command.clone(),
+                source,
+            })?;
+
+        if !status.success() {
+            return Err(Error::Command { command, status });
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        target_dir()
+    }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    /// The directory that cargo writes build artifacts to, as reported by `cargo metadata`.
+    /// This takes `CARGO_TARGET_DIR`, the cargo configuration and workspaces into account, and works from any subdirectory of the crate.
+    fn target_dir() -> Result<PathBuf, Error> {
+        #[derive(Deserialize)]
+        struct Metadata {
+            target_directory: PathBuf,
+        }
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let args = ["metadata", "--format-version", "1", "--no-deps"];
+        let command = format!("cargo {}", args.join(" "));
+        let spawn_error = |source| Error::Spawn {
+            command: command.clone(),
+            source,
+        };
 
-        let mut output = vec![];
+        let output = Command::new("cargo")
+            .args(args)
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(spawn_error)?;
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        if !output.status.success() {
+            return Err(Error::Command {
+                command,
+                status: output.status,
             });
-        });
-
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        serde_json::from_slice::<Metadata>(&output.stdout)
+            .map(|metadata| metadata.target_directory)
+            .map_err(|e| spawn_error(io::Error::other(e)))
+    }
 
-        Ok(output)
+    /// The path of a solution binary in the target directory that [`build`] returned.
+    fn executable(target_dir: &Path, year: Year, day: Day, is_release: bool) -> PathBuf {
+        let profile = if is_release { "release" } else { "debug" };
+        target_dir
+            .join(profile)
+            .join(format!("{year}-{day}{EXE_SUFFIX}"))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    /// Run the solution bin for a given day.
+    /// With the `prebuilt` target directory of [`build`], the binary is invoked directly instead of via `cargo run`.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        options: &super::RunOptions,
+        prebuilt: Option<&Path>,
+        output: &mut Output,
+    ) -> Result<Vec<PartResult>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if manifest::status(year, day).is_none() {
+            return Ok(vec![]);
+        }
+
+        let (program, mut args) = if let Some(target_dir) = prebuilt {
+            let executable = executable(target_dir, year, day, options.is_release);
+            (executable.to_string_lossy().into_owned(), vec![])
+        } else {
+            let mut args = vec![
+                "run".to_string(),
+                "--quiet".into(),
+                "--bin".into(),
+                format!("{year}-{day}"),
+            ];
+
+            if options.is_release {
+                args.push("--release".into());
+            }
+
+            args.push("--".into());
+            ("cargo".to_string(), args)
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        args.push("--format".into());
+        args.push(OutputFormat::Json.to_string());
+
+        if let Some(statistic) = options.time {
+            // mirror `--time` flag to child invocations.
+            args.push(format!("--time={statistic}"));
+        }
+
+        if let Some(timeout) = options.timeout {
+            args.push(format!("--timeout={timeout}"));
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if let Some(part) = options.selection.part {
+            args.push(format!("--part={part}"));
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        // spawn child command with piped stdout/stderr.
+        // forward output to stdout/stderr while grabbing result records.
+
+        let spawn_error = |source| Error::Spawn {
+            command: format!("{program} {}", args.join(" ")),
+            source,
+        };
+
+        let mut cmd = Command::new(&program)
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(spawn_error)?;
+
+        let (Some(stdout), Some(stderr)) = (cmd.stdout.take(), cmd.stderr.take()) else {
+            return Err(spawn_error(io::ErrorKind::BrokenPipe.into()));
+        };
+
+        let mut results = vec![];
+        let is_direct = matches!(output, Output::Direct);
+
+        // stderr is forwarded right away, or collected to be kept along with stdout.
+        let thread = thread::spawn(move || {
+            BufReader::new(stderr)
+                .lines()
+                .map_while(Result::ok)
+                .filter_map(|line| {
+                    if is_direct {
+                        eprintln!("{line}");
+                        return None;
+                    }
+                    Some(line)
+                })
+                .collect::<Vec<String>>()
+        });
+
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let line = match PartResult::from_json(&line) {
+                Some(result) => {
+                    results.push(result.clone());
+                    Line::Record(result, line)
                 }
+                None => Line::Text(line),
+            };
+            output.push(line, options.format);
+        }
 
-                timings.total_nanos += nanos;
-            });
+        // the thread only forwards output, it has nothing to report if it panicked.
+        for line in thread.join().unwrap_or_default() {
+            output.push(Line::Error(line), options.format);
+        }
 
-        timings
-    }
+        let status = cmd.wait().map_err(spawn_error)?;
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+        if status.success() {
+            return Ok(results);
+        }
+
+        match Error::solution_failed(status) {
+            // the timed out part has been reported, the remaining days still run.
+            Error::TimedOut
+                if results
+                    .iter()
+                    .any(|result| result.status == PartStatus::TimedOut) =>
+            {
+                Ok(results)
+            }
+            e => Err(e),
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    pub fn collect_timings(results: &[PartResult], day: Day) -> super::Timings {
+        let mut timings = super::Timings::new(day);
+
+        for result in results {
+            let timing = match result.status {
+                PartStatus::Unsolved => continue,
+                PartStatus::Open => PartTiming::Open,
+                PartStatus::TimedOut => PartTiming::TimedOut(result.duration),
+                PartStatus::Solved | PartStatus::Parsed => PartTiming::Solved(result.duration),
+            };
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+            timings.add(result.part, timing, Some(result.stats.samples));
+        }
 
-        Some((str_timing, parsed_timing))
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -202,53 +768,97 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::collect_timings;
+        use crate::template::{readme_benchmarks::PartTiming, runner::PartResult};
+        use std::time::Duration;
 
         use crate::day;
 
+        fn parse(lines: &[&str]) -> Vec<PartResult> {
+            lines
+                .iter()
+                .filter_map(|line| PartResult::from_json(line))
+                .collect()
+        }
+
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
+            let res = collect_timings(
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":1,"status":"solved","answer":"0","duration_nanos":74,"samples":100000,"min_nanos":70,"median_nanos":74,"p95_nanos":80,"mean_nanos":75,"std_dev_nanos":2,"outliers":0}"#,
+                    r#"{"year":2023,"day":1,"part":2,"status":"solved","answer":"10","duration_nanos":74130000,"samples":99999,"min_nanos":70,"median_nanos":74130000,"p95_nanos":80,"mean_nanos":75,"std_dev_nanos":2,"outliers":0}"#,
+                    "",
+                ]),
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1, PartTiming::Solved(Duration::from_nanos(74)));
+            assert_eq!(
+                res.part_2,
+                PartTiming::Solved(Duration::from_nanos(74130000))
+            );
+            assert_eq!(res.samples, Some(99999));
         }
 
         #[test]
         fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
+            let res = collect_timings(
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":1,"status":"solved","answer":"@ @ @ ( ) ms (2s @ 5 samples)","duration_nanos":2000000000,"samples":5,"min_nanos":1,"median_nanos":2000000000,"p95_nanos":1,"mean_nanos":1,"std_dev_nanos":0,"outliers":0}"#,
+                    "Part 2: 10s (100ms @ 1 samples)",
+                    r#"{"year":2023,"day":1,"part":2,"status":"solved","answer":"10s","duration_nanos":100000000,"samples":1,"min_nanos":1,"median_nanos":100000000,"p95_nanos":1,"mean_nanos":1,"std_dev_nanos":0,"outliers":0}"#,
+                ]),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1, PartTiming::Solved(Duration::from_secs(2)));
+            assert_eq!(res.part_2, PartTiming::Solved(Duration::from_millis(100)));
+            assert_eq!(res.samples, Some(1));
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
+            let res = collect_timings(
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":1,"status":"unsolved","answer":null,"duration_nanos":10,"samples":1,"min_nanos":10,"median_nanos":10,"p95_nanos":10,"mean_nanos":10,"std_dev_nanos":0,"outliers":0}"#,
+                    r#"{"year":2023,"day":1,"part":2,"status":"unsolved","answer":null,"duration_nanos":10,"samples":1,"min_nanos":10,"median_nanos":10,"p95_nanos":10,"mean_nanos":10,"std_dev_nanos":0,"outliers":0}"#,
+                    "",
+                ]),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.part_1, PartTiming::Unsolved);
+            assert_eq!(res.part_2, PartTiming::Unsolved);
+            assert_eq!(res.samples, None);
+        }
+
+        #[test]
+        fn test_open_parts() {
+            let res = collect_timings(
+                &parse(&[
+                    r#"{"year":2023,"day":20,"part":1,"status":"solved","answer":"1","duration_nanos":20,"samples":10,"min_nanos":1,"median_nanos":20,"p95_nanos":1,"mean_nanos":1,"std_dev_nanos":0,"outliers":0}"#,
+                    r#"{"year":2023,"day":20,"part":2,"status":"open","answer":null,"duration_nanos":10,"samples":100,"min_nanos":10,"median_nanos":10,"p95_nanos":10,"mean_nanos":10,"std_dev_nanos":0,"outliers":0}"#,
+                ]),
+                day!(20),
+            );
+            assert_approx_eq!(res.total_nanos, 20_f64);
+            assert_eq!(res.part_2, PartTiming::Open);
+            assert_eq!(res.samples, Some(10));
+        }
+
+        #[test]
+        fn test_parse_step() {
+            let res = collect_timings(
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":0,"status":"parsed","answer":null,"duration_nanos":1000,"samples":10,"min_nanos":1,"median_nanos":1000,"p95_nanos":1,"mean_nanos":1,"std_dev_nanos":0,"outliers":0}"#,
+                    r#"{"year":2023,"day":1,"part":1,"status":"solved","answer":"1","duration_nanos":20,"samples":10,"min_nanos":1,"median_nanos":20,"p95_nanos":1,"mean_nanos":1,"std_dev_nanos":0,"outliers":0}"#,
+                ]),
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1020_f64);
+            assert_eq!(res.parse, Some(Duration::from_micros(1)));
+            assert_eq!(res.part_1, PartTiming::Solved(Duration::from_nanos(20)));
+            assert_eq!(res.part_2, PartTiming::Unsolved);
         }
     }
 }
@@ -1,15 +1,20 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::{
+    aoc_backend::{self, get_input_path, get_puzzle_path},
+    Error,
+};
+use crate::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(year: Year, day: Day) -> Result<(), Error> {
+    aoc_backend::backend()?.download(year, day)?;
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        get_input_path(year, day)
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        get_puzzle_path(year, day)
+    );
+    Ok(())
 }
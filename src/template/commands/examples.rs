@@ -0,0 +1,167 @@
+use std::{fs, io};
+
+use crate::template::{
+    aoc_backend::get_puzzle_path,
+    create_parent_dir, get_data_path,
+    manifest::get_path_for_bin,
+    puzzle::{self, Example},
+    Error,
+};
+use crate::{Day, Year};
+
+/// Extracts the examples from the downloaded puzzle description of a day.
+pub fn handle(year: Year, day: Day) -> Result<(), Error> {
+    let puzzle_path = get_puzzle_path(year, day);
+
+    match fs::read_to_string(&puzzle_path) {
+        Ok(puzzle) => extract(year, day, &puzzle),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(Error::Usage(format!(
+            "the puzzle description of day {day} of {year} has not been downloaded yet. Run `cargo download {} --year {year}` first.",
+            day.into_inner()
+        ))),
+        Err(e) => Err(Error::io(puzzle_path)(e)),
+    }
+}
+
+/// Writes the example inputs to `data/<year>/examples` and `data/<year>/examples_part2`, and fills the example answers into the tests of the day.
+/// Example files that are not empty and tests that already assert an answer are left untouched.
+pub fn extract(year: Year, day: Day, puzzle: &str) -> Result<(), Error> {
+    let examples = puzzle::examples(puzzle);
+    let module_path = get_path_for_bin(year, day);
+    let module = fs::read_to_string(&module_path).ok();
+    let mut filled = module.clone();
+
+    write_example(year, day, "examples", &examples.part_one)?;
+    fill_answer(&mut filled, "test_part_one", &examples.part_one, None);
+
+    if let Some(part_two) = &examples.part_two {
+        let folder = part_two.input.as_ref().map(|_| "examples_part2");
+        if let Some(folder) = folder {
+            write_example(year, day, folder, part_two)?;
+        }
+        fill_answer(&mut filled, "test_part_two", part_two, folder);
+    }
+
+    match filled {
+        Some(filled) if Some(&filled) != module.as_ref() => {
+            fs::write(&module_path, filled).map_err(Error::io(&module_path))?;
+            println!("Filled example answers into \"{module_path}\"");
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn write_example(year: Year, day: Day, folder: &str, example: &Example) -> Result<(), Error> {
+    let path = get_data_path(folder, year, day);
+
+    let Some(input) = &example.input else {
+        println!("No example found for \"{path}\"");
+        return Ok(());
+    };
+
+    let is_empty = fs::read_to_string(&path).map_or(true, |content| content.trim().is_empty());
+    if !is_empty {
+        println!("Kept existing example file \"{path}\"");
+        return Ok(());
+    }
+
+    create_parent_dir(&path)?;
+    fs::write(&path, input).map_err(Error::io(&path))?;
+    println!("Wrote example to \"{path}\"");
+    Ok(())
+}
+
+fn fill_answer(module: &mut Option<String>, test: &str, example: &Example, folder: Option<&str>) {
+    let (Some(content), Some(answer)) = (module.as_mut(), &example.answer) else {
+        return;
+    };
+
+    // answers are asserted as numbers, textual answers have to be filled in by hand.
+    if answer.parse::<i64>().is_err() {
+        println!("Not filling in the answer \"{answer}\" of {test}, it is not a number.");
+        return;
+    }
+
+    if let Some(filled) = fill_test(content, test, answer, folder) {
+        *content = filled;
+    }
+}
+
+/// Replaces `assert_eq!(result, None);` in the given test with the answer, and reads the example from `folder` if given.
+/// Returns [`None`] if the test does not exist or already asserts an answer.
+fn fill_test(module: &str, test: &str, answer: &str, folder: Option<&str>) -> Option<String> {
+    let start = module.find(&format!("fn {test}("))?;
+    let end = module[start + 1..]
+        .find("fn test_")
+        .map_or(module.len(), |end| start + 1 + end);
+
+    let body = &module[start..end];
+    if !body.contains("assert_eq!(result, None);") {
+        return None;
+    }
+
+    let mut body = body.replacen(
+        "assert_eq!(result, None);",
+        &format!("assert_eq!(result, Some({answer}));"),
+        1,
+    );
+    if let Some(folder) = folder {
+        body = body.replacen("\"examples\"", &format!("\"{folder}\""), 1);
+    }
+
+    Some(format!("{}{body}{}", &module[..start], &module[end..]))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::fill_test;
+
+    const MODULE: &str = r#"advent_of_code::solution!(2023, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn fills_answer_of_part_one() {
+        let filled = fill_test(MODULE, "test_part_one", "142", None).unwrap();
+        assert!(filled.contains(
+            "assert_eq!(result, Some(142));\n    }\n\n    #[test]\n    fn test_part_two"
+        ));
+        assert_eq!(filled.matches("assert_eq!(result, None);").count(), 1);
+        assert_eq!(filled.matches("\"examples\"").count(), 2);
+    }
+
+    #[test]
+    fn fills_answer_and_example_of_part_two() {
+        let filled = fill_test(MODULE, "test_part_two", "281", Some("examples_part2")).unwrap();
+        assert!(filled.contains(
+            "read_file(\"examples_part2\", YEAR, DAY));\n        assert_eq!(result, Some(281));"
+        ));
+        assert!(filled
+            .contains("read_file(\"examples\", YEAR, DAY));\n        assert_eq!(result, None);"));
+    }
+
+    #[test]
+    fn keeps_filled_answers() {
+        let filled = fill_test(MODULE, "test_part_one", "142", None).unwrap();
+        assert_eq!(fill_test(&filled, "test_part_one", "7", None), None);
+        assert_eq!(fill_test(MODULE, "test_part_three", "7", None), None);
+    }
+}
@@ -1,94 +1,276 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
-    process,
+    path::Path,
 };
 
-use crate::Day;
-
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+use crate::template::{
+    aoc_backend::get_puzzle_path,
+    commands::examples,
+    data_dir, get_data_path,
+    manifest::{self, get_path_for_bin},
+    puzzle, Error,
+};
+use crate::{Day, Year};
 
-pub fn part_one(input: &str) -> Option<u32> {
-    None
+/// The directory of the templates, in the root of the project, see [`data_dir::root`].
+fn templates_dir() -> String {
+    data_dir::project_path("templates")
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+/// The module that registers the solutions, in the root of the project.
+fn solutions_path() -> String {
+    data_dir::project_path("src/solutions.rs")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub const DEFAULT_TEMPLATE: &str = "default";
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+/// Reads the template `templates/<name>.rs`.
+fn load_template(name: &str) -> Result<String, Error> {
+    let path = format!("{}/{name}.rs", templates_dir());
+
+    if !Path::new(&path).exists() {
+        return Err(Error::Usage(format!(
+            "there is no template \"{name}\". Available templates: {}.",
+            available_templates().join(", ")
+        )));
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    fs::read_to_string(&path).map_err(Error::io(path))
+}
+
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            Some(name.to_str()?.strip_suffix(".rs")?.to_string())
+        })
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+/// Replaces every `{{variable}}` in the template. Fails on unknown variables, which are most likely typos.
+fn render(template: &str, variables: &[(&str, &str)]) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start + 2..];
+
+        let end = rest.find("}}").ok_or("unclosed \"{{\".")?;
+        let name = rest[..end].trim();
+        let (_, value) = variables
+            .iter()
+            .find(|(variable, _)| *variable == name)
+            .ok_or_else(|| format!("unknown variable \"{{{{{name}}}}}\"."))?;
+
+        rendered.push_str(value);
+        rest = &rest[end + 2..];
     }
+
+    rendered.push_str(rest);
+    Ok(rendered)
 }
-"#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn safe_create_file(path: &str) -> Result<File, Error> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(Error::io(path))
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &str) -> Result<File, Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .map_err(Error::io(path))
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Adds the module of a day to the list of solutions that are compiled into the CLI.
+/// Returns [`None`] if the day is registered already.
+fn register_solution(content: &str, year: Year, day: Day) -> Result<Option<String>, String> {
+    let entry = format!("    y{year}_day{day} => \"bin/{year}-{day}.rs\",\n");
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
-
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+    if content.contains(&entry) {
+        return Ok(None);
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let pos = content
+        .rfind("\n}")
+        .ok_or("could not find end of solution list.")?;
+
+    let mut content = content.to_string();
+    content.insert_str(pos + 1, &entry);
+    Ok(Some(content))
+}
+
+/// The changes of a scaffold run so far, so that they can be undone if a later step fails.
+#[derive(Default)]
+struct Changes {
+    created: Vec<String>,
+    /// Files that were modified, along with their previous content.
+    modified: Vec<(String, String)>,
+}
+
+impl Changes {
+    /// Removes the created files and restores the modified ones. This is best effort, the original error is what gets reported.
+    fn undo(self) {
+        for (path, content) in self.modified.into_iter().rev() {
+            let _ = fs::write(path, content);
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+        for path in self.created.into_iter().rev() {
+            let _ = fs::remove_file(path);
         }
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+/// Creates the module of a day from the template `templates/<template>.rs`, as well as its input and example files.
+/// The day is added to the manifest of the year. If the puzzle description was downloaded already, its examples are extracted as well.
+///
+/// Everything that could fail on the content of existing files is checked before the first file is written.
+/// If writing fails halfway, the files of the day are removed again, so that scaffolding can simply be retried.
+pub fn handle(year: Year, day: Day, template: &str, answer_type: &str) -> Result<(), Error> {
+    let template_path = format!("{}/{template}.rs", templates_dir());
+    let puzzle = fs::read_to_string(get_puzzle_path(year, day)).ok();
+    let title = puzzle
+        .as_deref()
+        .and_then(puzzle::title)
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+    let module = render(
+        &load_template(template)?,
+        &[
+            ("year", &year.to_string()),
+            ("day", &day.into_inner().to_string()),
+            ("title", &title),
+            ("answer_type", answer_type),
+        ],
+    )
+    .map_err(|reason| Error::InvalidFile {
+        path: template_path,
+        reason,
+    })?;
+
+    let solutions_path = solutions_path();
+    let solutions = fs::read_to_string(&solutions_path).map_err(Error::io(&solutions_path))?;
+    let registered =
+        register_solution(&solutions, year, day).map_err(|reason| Error::InvalidFile {
+            path: solutions_path,
+            reason,
+        })?;
+
+    // a manifest that cannot be read cannot be updated either.
+    manifest::read(year)?;
+
+    let mut changes = Changes::default();
+    if let Err(e) = create_day(year, day, &module, registered, solutions, &mut changes) {
+        changes.undo();
+        return Err(e);
+    }
+
+    if let Some(puzzle) = puzzle {
+        examples::extract(year, day, &puzzle)?;
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    if Year::from_env() == Some(year) {
+        println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {year}` to run your solution.",
+            day
+        );
+    }
+
+    Ok(())
+}
+
+/// Writes the files of a scaffolded day and records every change in `changes`.
+fn create_day(
+    year: Year,
+    day: Day,
+    module: &str,
+    registered: Option<String>,
+    solutions: String,
+    changes: &mut Changes,
+) -> Result<(), Error> {
+    let input_path = get_data_path("inputs", year, day);
+    let example_path = get_data_path("examples", year, day);
+    let module_path = get_path_for_bin(year, day);
+
+    for dir in ["inputs", "examples"] {
+        let dir = data_dir::path(format!("{year}/{dir}"));
+        fs::create_dir_all(&dir).map_err(Error::io(dir))?;
+    }
+
+    let mut file = safe_create_file(&module_path)?;
+    changes.created.push(module_path.clone());
+    file.write_all(module.as_bytes())
+        .map_err(Error::io(&module_path))?;
+    println!("Created module file \"{}\"", &module_path);
+
+    // the input may have been downloaded before the day was scaffolded.
+    for (path, kind) in [(&input_path, "input"), (&example_path, "example")] {
+        if Path::new(path).exists() {
+            println!("Kept existing {kind} file \"{path}\"");
+        } else {
+            create_file(path)?;
+            changes.created.push(path.clone());
+            println!("Created empty {kind} file \"{path}\"");
+        }
+    }
+
+    let solutions_path = solutions_path();
+    if let Some(registered) = registered {
+        fs::write(&solutions_path, registered).map_err(Error::io(&solutions_path))?;
+        changes.modified.push((solutions_path.clone(), solutions));
+    }
+    println!("Registered solution in \"{solutions_path}\"");
+
+    manifest::add(year, day)?;
+    println!("Added day to \"{}\"", manifest::get_manifest_path(year));
+
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{register_solution, render};
+    use crate::{day, year};
+
+    #[test]
+    fn renders_variables() {
+        let variables = [("day", "5"), ("answer_type", "u64")];
+        assert_eq!(
+            render("solution!({{day}}) -> {{ answer_type }}", &variables).unwrap(),
+            "solution!(5) -> u64"
+        );
+        assert!(render("{{dya}}", &variables).is_err());
+        assert!(render("{{day", &variables).is_err());
+    }
+
+    #[test]
+    fn registers_solutions() {
+        let solutions = "solutions! {\n    y2023_day01 => \"bin/2023-01.rs\",\n}\n";
+        let registered = register_solution(solutions, year!(2023), day!(2))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            registered,
+            "solutions! {\n    y2023_day01 => \"bin/2023-01.rs\",\n    y2023_day02 => \"bin/2023-02.rs\",\n}\n"
+        );
+
+        assert_eq!(
+            register_solution(&registered, year!(2023), day!(2)),
+            Ok(None)
+        );
+        assert!(register_solution("", year!(2023), day!(2)).is_err());
+    }
 }
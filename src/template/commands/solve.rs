@@ -1,31 +1,272 @@
+use std::path::Path;
 use std::process::{Command, Stdio};
+use std::{fs, thread, time::Duration, time::SystemTime};
 
-use crate::Day;
+use crate::template::{
+    aoc_backend::get_input_path,
+    commands::all,
+    get_data_path,
+    manifest::get_path_for_bin,
+    runner::{InputSource, OutputFormat, PartResult},
+    Error, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{Day, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How often `--watch` checks the watched files for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
 
-    if release {
+/// Runs the solution binary of a day against the given input, with the given overrides of its parameters.
+pub fn handle(
+    year: Year,
+    day: Day,
+    options: &all::RunOptions,
+    input: &InputSource,
+    params: &[String],
+    submit_part: Option<u8>,
+) -> Result<(), Error> {
+    check_exists(year, day)?;
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
+
+    if options.is_release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
+    match input {
+        InputSource::Puzzle => {}
+        InputSource::Example => cmd_args.push("--example".to_string()),
+        InputSource::File(path) => cmd_args.push(format!("--input={path}")),
+        InputSource::Stdin => cmd_args.push("--input=-".to_string()),
+    }
+
+    for param in params {
+        cmd_args.push(format!("--param={param}"));
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
-        cmd_args.push("--time".to_string());
+    if options.format != OutputFormat::default() {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(options.format.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
+    if let Some(statistic) = options.time {
+        cmd_args.push(format!("--time={statistic}"));
+    }
+
+    if let Some(timeout) = options.timeout {
+        cmd_args.push(format!("--timeout={timeout}"));
+    }
+
+    let spawn_error = |source| Error::Spawn {
+        command: format!("cargo {}", cmd_args.join(" ")),
+        source,
+    };
+
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()
+        .map_err(spawn_error)?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::solution_failed(status).for_day(year, day))
+    }
+}
+
+/// Runs the tests of a day and, if they pass, its solution on the real input.
+/// Both run again whenever the module, the examples or the input of the day change, until the command is interrupted.
+/// After every run but the first, the answers that changed since the previous run are listed.
+pub fn watch(year: Year, day: Day, options: &all::RunOptions) -> Result<(), Error> {
+    check_exists(year, day)?;
+
+    let paths = [
+        get_path_for_bin(year, day),
+        get_data_path("examples", year, day),
+        get_data_path("examples_part2", year, day),
+        get_input_path(year, day),
+    ];
+    let mut previous: Option<Vec<PartResult>> = None;
+
+    loop {
+        let modified = modification_times(&paths);
+
+        if run_tests(year, day, options.is_release)? {
+            match all::run_day(year, day, options) {
+                Ok(results) => {
+                    if let Some(previous) = &previous {
+                        println!();
+                        print_changes(previous, &results);
+                    }
+                    previous = Some(results);
+                }
+                Err(e @ Error::Spawn { .. }) => return Err(e),
+                Err(e) => eprintln!("Error: {}", e.for_day(year, day)),
+            }
+        } else {
+            println!("The tests failed, the input was not run.");
+        }
+
+        println!();
+        println!("{ANSI_ITALIC}Watching for changes, press Ctrl-C to stop.{ANSI_RESET}");
+
+        while modification_times(&paths) == modified {
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        println!();
+        println!("{ANSI_BOLD}Change detected, running day {day} again.{ANSI_RESET}");
+    }
+}
+
+fn check_exists(year: Year, day: Day) -> Result<(), Error> {
+    if Path::new(&get_path_for_bin(year, day)).exists() {
+        Ok(())
+    } else {
+        Err(Error::Usage(format!(
+            "there is no solution for day {day} of {year}. Run `cargo scaffold {} --year {year}` to create one.",
+            day.into_inner()
+        )))
+    }
+}
+
+/// A missing file has no modification time, so creating or deleting a file counts as a change as well.
+fn modification_times(paths: &[String]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .collect()
+}
+
+/// Runs the tests of a day, which check the solution against the examples. Returns whether they passed.
+fn run_tests(year: Year, day: Day, release: bool) -> Result<bool, Error> {
+    let mut args = vec![
+        "test".to_string(),
+        "--quiet".into(),
+        "--bin".into(),
+        format!("{year}-{day}"),
+    ];
+
+    if release {
+        args.push("--release".into());
+    }
+
+    Command::new("cargo")
+        .args(&args)
+        .status()
+        .map(|status| status.success())
+        .map_err(|source| Error::Spawn {
+            command: format!("cargo {}", args.join(" ")),
+            source,
+        })
+}
+
+fn print_changes(previous: &[PartResult], results: &[PartResult]) {
+    let changes = answer_changes(previous, results);
+
+    if changes.is_empty() {
+        println!("The answers did not change.");
+        return;
+    }
+
+    println!("Changes since the last run:");
+    for change in changes {
+        println!("{change}");
+    }
+}
+
+/// Describes the parts whose answer differs from the previous run, e.g. `Part 1: 142 → 145`.
+/// Multi-line answers are only reported as changed.
+fn answer_changes(previous: &[PartResult], results: &[PartResult]) -> Vec<String> {
+    let answer = |results: &[PartResult], part: u8| {
+        results
+            .iter()
+            .find(|result| result.part == part)
+            .and_then(|result| result.answer.clone())
+    };
+
+    let mut parts: Vec<u8> = previous
+        .iter()
+        .chain(results)
+        .map(|result| result.part)
+        .filter(|&part| part > 0)
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
 
-    cmd.wait().unwrap();
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            let (before, after) = (answer(previous, part), answer(results, part));
+            if before == after {
+                return None;
+            }
+
+            let is_multi_line = [&before, &after]
+                .iter()
+                .any(|answer| answer.as_ref().is_some_and(|answer| answer.contains('\n')));
+            if is_multi_line {
+                return Some(format!("Part {part}: changed"));
+            }
+
+            let show = |answer: Option<String>| answer.unwrap_or_else(|| "✖".into());
+            Some(format!("Part {part}: {} → {}", show(before), show(after)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::answer_changes;
+    use crate::template::runner::{BenchStats, PartResult, Statistic};
+    use crate::{day, year};
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        let stats = BenchStats::from_samples(&[Duration::from_millis(1)]);
+        PartResult::new(
+            year!(2023),
+            day!(1),
+            part,
+            answer.map(Into::into),
+            stats,
+            Statistic::Median,
+        )
+    }
+
+    #[test]
+    fn lists_changed_answers() {
+        let previous = [result(0, None), result(1, Some("142")), result(2, None)];
+        let results = [
+            result(0, None),
+            result(1, Some("145")),
+            result(2, Some("281")),
+        ];
+        assert_eq!(
+            answer_changes(&previous, &results),
+            ["Part 1: 142 → 145", "Part 2: ✖ → 281"]
+        );
+
+        assert!(answer_changes(&results, &results).is_empty());
+        assert_eq!(
+            answer_changes(&[result(1, Some("#.\n.#"))], &[result(1, Some("#.\n##"))]),
+            ["Part 1: changed"]
+        );
+    }
 }
@@ -0,0 +1,207 @@
+use std::{fmt::Display, fs, time::Duration};
+
+use crate::template::{
+    answers,
+    aoc_backend::{get_input_path, get_puzzle_path},
+    get_data_path,
+    manifest::{self, DayStatus},
+    readme_stars,
+    timings::{self, LastTiming},
+    Error, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{all_days, Day, Year, MAX_STARS};
+
+/// The state of a single part, combining the manifest with the accepted answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PartState {
+    NotScaffolded,
+    Open,
+    Solved,
+    /// Solved, and the answer was accepted by the website.
+    Accepted,
+}
+
+impl PartState {
+    fn has_star(self) -> bool {
+        matches!(self, PartState::Solved | PartState::Accepted)
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            PartState::NotScaffolded => ' ',
+            PartState::Open => '.',
+            PartState::Solved | PartState::Accepted => '*',
+        }
+    }
+}
+
+impl Display for PartState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PartState::NotScaffolded => "-",
+            PartState::Open => "open",
+            PartState::Solved => "solved",
+            PartState::Accepted => "accepted",
+        })
+    }
+}
+
+/// Everything that is known locally about a day.
+struct DayOverview {
+    day: Day,
+    parts: [PartState; 2],
+    has_input: bool,
+    has_puzzle: bool,
+    has_example: bool,
+    timing: Option<LastTiming>,
+}
+
+impl DayOverview {
+    fn read(year: Year, day: Day, status: Option<DayStatus>, timing: Option<LastTiming>) -> Self {
+        let accepted = answers::read(year, day);
+        let part = |part: u8| match status {
+            _ if accepted.contains_key(&part) => PartState::Accepted,
+            None => PartState::NotScaffolded,
+            Some(status) if status.is_open(part) => PartState::Open,
+            Some(_) => PartState::Solved,
+        };
+
+        Self {
+            day,
+            parts: [part(1), part(2)],
+            has_input: is_present(&get_input_path(year, day)),
+            has_puzzle: is_present(&get_puzzle_path(year, day)),
+            has_example: is_present(&get_data_path("examples", year, day)),
+            timing,
+        }
+    }
+
+    fn is_started(&self) -> bool {
+        self.parts[0] != PartState::NotScaffolded || self.has_input || self.has_puzzle
+    }
+}
+
+/// A file counts as present if it is not empty, scaffolding creates empty input and example files.
+fn is_present(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Prints a calendar of the progress of the given years, followed by the details of every started day.
+/// With `update_readme`, the stars that were accepted according to the local records are written to the readme as well.
+pub fn handle(years: &[Year], update_readme: bool) -> Result<(), Error> {
+    if years.is_empty() {
+        println!("No days have been scaffolded yet.");
+        return Ok(());
+    }
+
+    for (i, &year) in years.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let days = manifest::read(year)?;
+        let timings = timings::read(year);
+        let overviews: Vec<DayOverview> = all_days()
+            .map(|day| {
+                let timing = timings.get(&day).copied();
+                DayOverview::read(year, day, days.get(&day).copied(), timing)
+            })
+            .collect();
+
+        print_calendar(year, &overviews);
+        println!();
+        print_details(&overviews);
+    }
+
+    if update_readme {
+        let stars = readme_stars::update(years)?;
+        println!();
+        println!("Successfully updated README with stars ({stars} in total).");
+    }
+
+    Ok(())
+}
+
+fn print_calendar(year: Year, overviews: &[DayOverview]) {
+    let total = overviews
+        .iter()
+        .flat_map(|overview| overview.parts)
+        .filter(|part| part.has_star())
+        .count();
+
+    println!("{ANSI_BOLD}== {year} =={ANSI_RESET} {total}/{MAX_STARS} stars");
+    println!();
+    println!("Mon    Tue    Wed    Thu    Fri    Sat    Sun");
+
+    let offset = first_weekday(year);
+    let mut line = "       ".repeat(offset);
+
+    for (i, overview) in overviews.iter().enumerate() {
+        line.push_str(&format!(
+            "{} {}{}  ",
+            overview.day,
+            overview.parts[0].symbol(),
+            overview.parts[1].symbol()
+        ));
+
+        // a week ends on sunday.
+        if (offset + i) % 7 == 6 {
+            println!("{}", line.trim_end());
+            line.clear();
+        }
+    }
+
+    if !line.is_empty() {
+        println!("{}", line.trim_end());
+    }
+
+    println!("{ANSI_ITALIC}* solved, . open{ANSI_RESET}");
+}
+
+fn print_details(overviews: &[DayOverview]) {
+    let check = |present: bool| if present { "✔" } else { "✖" };
+
+    println!("Day  Part 1    Part 2    Input  Puzzle  Example  Last timing");
+
+    for overview in overviews.iter().filter(|overview| overview.is_started()) {
+        println!(
+            "{}   {:<8}  {:<8}  {}      {}       {}        {}",
+            overview.day,
+            overview.parts[0].to_string(),
+            overview.parts[1].to_string(),
+            check(overview.has_input),
+            check(overview.has_puzzle),
+            check(overview.has_example),
+            overview.timing.map_or_else(|| "-".into(), format_timing)
+        );
+    }
+}
+
+fn format_timing(timing: LastTiming) -> String {
+    let format = |duration: Option<Duration>| {
+        duration.map_or_else(|| "-".into(), |duration| format!("{duration:.1?}"))
+    };
+    format!("{} / {}", format(timing.part_1), format(timing.part_2))
+}
+
+/// The weekday of December 1st, counted from Monday as `0`.
+fn first_weekday(year: Year) -> usize {
+    let y = usize::from(year.into_inner());
+    // Sakamoto's method, with the offset of December. Yields `0` for Sunday.
+    let sunday_based = (y + y / 4 - y / 100 + y / 400 + 4 + 1) % 7;
+    (sunday_based + 6) % 7
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::first_weekday;
+    use crate::year;
+
+    #[test]
+    fn finds_first_weekday_of_december() {
+        // December 1st 2023 was a Friday, 2015 a Tuesday and 2024 a Sunday.
+        assert_eq!(first_weekday(year!(2023)), 4);
+        assert_eq!(first_weekday(year!(2015)), 1);
+        assert_eq!(first_weekday(year!(2024)), 6);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::template::{
+    answers::{self, Verification},
+    commands::all,
+    runner::PartStatus,
+    solution::Registry,
+    Error, ANSI_BOLD, ANSI_RESET,
+};
+use crate::Year;
+
+/// Runs every day of the given years and compares the answers against `data/<year>/answers`.
+/// Fails if any answer does not match its accepted answer or any day failed to run.
+/// A part that was aborted after exceeding its time budget has no answer, so it counts as a mismatch if it has an accepted answer.
+pub fn handle(
+    years: &[Year],
+    options: &all::RunOptions,
+    registry: Option<&Registry>,
+) -> Result<(), Error> {
+    let mut results = vec![];
+    let mut failed_days = 0;
+
+    for &year in years {
+        let run = all::run_days(year, options, registry)?;
+        results.extend(run.results);
+        failed_days += run.failed_days;
+    }
+
+    let mut correct = 0;
+    let mut unknown = 0;
+    let mut mismatches = vec![];
+
+    for result in results
+        .iter()
+        .flat_map(|(_, results)| results)
+        .filter(|result| result.status != PartStatus::Parsed)
+    {
+        match result.verification() {
+            Verification::Correct => correct += 1,
+            Verification::Unknown => unknown += 1,
+            Verification::Incorrect => mismatches.push(result),
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {correct} correct, {} incorrect, {unknown} without accepted answer.",
+        mismatches.len()
+    );
+
+    for result in &mismatches {
+        eprintln!(
+            "{} day {} part {}: expected {}, got {} (see \"{}\").",
+            result.year,
+            result.day,
+            result.part,
+            result.expected.as_deref().unwrap_or_default(),
+            result.answer.as_deref().unwrap_or("no answer"),
+            answers::get_answers_path(result.year, result.day)
+        );
+    }
+
+    match (mismatches.len(), failed_days) {
+        (0, 0) => Ok(()),
+        (0, n) => Err(Error::DaysFailed(n)),
+        (n, _) => Err(Error::WrongAnswers(n)),
+    }
+}
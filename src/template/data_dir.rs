@@ -0,0 +1,181 @@
+/// Module that finds the `data` directory and the root of the project, so that solutions, their tests and the commands can run from any working directory.
+/// The data directory is looked up in this order:
+/// 1. The directory in `AOC_DATA_DIR`. If the variable is set, no other location is tried.
+/// 2. `data` in the directory of the manifest that `cargo run` and `cargo test` pass via `CARGO_MANIFEST_DIR`.
+/// 3. The closest `data` directory next to a `Cargo.toml`, searching upwards from the working directory.
+/// 4. `data` in the directory of the manifest the template was built from.
+///
+/// The root of the project holds the readme, the templates and the solutions. It is looked up in the same way,
+/// as the directory of the manifest instead of its `data` directory, and does not depend on `AOC_DATA_DIR`.
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::template::Error;
+
+const OVERRIDE_VARIABLE: &str = "AOC_DATA_DIR";
+const MARKER: &str = "Cargo.toml";
+const DIR_NAME: &str = "data";
+
+/// Returns the data directory. It is looked up once per process.
+/// A directory below the working directory is returned as a relative path, so that messages stay short.
+pub fn find() -> Result<&'static Path, Error> {
+    static DATA_DIR: OnceLock<Result<PathBuf, Vec<String>>> = OnceLock::new();
+
+    let data_dir = DATA_DIR.get_or_init(|| {
+        let cwd = env::current_dir().ok();
+        let dir = discover(
+            env::var_os(OVERRIDE_VARIABLE).map(PathBuf::from),
+            env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from),
+            cwd.as_deref(),
+        )?;
+
+        Ok(match cwd.as_deref().map(|cwd| dir.strip_prefix(cwd)) {
+            Some(Ok(relative)) if !relative.as_os_str().is_empty() => relative.to_path_buf(),
+            _ => dir,
+        })
+    });
+
+    match data_dir {
+        Ok(dir) => Ok(dir),
+        Err(searched) => Err(Error::DataDirNotFound(searched.clone())),
+    }
+}
+
+/// The path of a file or directory in the data directory, e.g. `path("2023/manifest.txt")`.
+/// Falls back to `data` in the working directory if no data directory was found, so that it can be created there.
+#[must_use]
+pub fn path(relative: impl AsRef<Path>) -> String {
+    find()
+        .unwrap_or_else(|_| Path::new(DIR_NAME))
+        .join(relative)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns the root directory of the project. It is looked up once per process.
+/// The working directory is returned as an empty path and a directory below it as a relative path, so that messages stay short.
+pub fn root() -> &'static Path {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+    ROOT.get_or_init(|| {
+        let cwd = env::current_dir().ok();
+        let root = candidates(
+            env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from),
+            cwd.as_deref(),
+        )
+        .find(|dir| dir.join(MARKER).is_file())
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+
+        match cwd.as_deref().map(|cwd| root.strip_prefix(cwd)) {
+            Some(Ok(relative)) => relative.to_path_buf(),
+            _ => root,
+        }
+    })
+}
+
+/// The path of a file or directory in the root of the project, e.g. `project_path("README.md")`.
+#[must_use]
+pub fn project_path(relative: impl AsRef<Path>) -> String {
+    root().join(relative).to_string_lossy().into_owned()
+}
+
+/// The directories that may hold the project, in the order they are tried:
+/// the manifest directory passed by cargo, the working directory and its parents that contain a `Cargo.toml`,
+/// and the directory the template was built from.
+fn candidates(
+    manifest_dir: Option<PathBuf>,
+    cwd: Option<&Path>,
+) -> impl Iterator<Item = PathBuf> + '_ {
+    let upwards = cwd
+        .into_iter()
+        .flat_map(Path::ancestors)
+        .filter(|dir| dir.join(MARKER).is_file())
+        .map(Path::to_path_buf);
+
+    manifest_dir
+        .into_iter()
+        .chain(upwards)
+        .chain([PathBuf::from(env!("CARGO_MANIFEST_DIR"))])
+}
+
+/// Returns the first data directory that exists, or a description of every location that was searched.
+fn discover(
+    data_dir: Option<PathBuf>,
+    manifest_dir: Option<PathBuf>,
+    cwd: Option<&Path>,
+) -> Result<PathBuf, Vec<String>> {
+    if let Some(dir) = data_dir {
+        return if dir.is_dir() {
+            Ok(dir)
+        } else {
+            Err(vec![format!(
+                "{} (from {OVERRIDE_VARIABLE})",
+                dir.display()
+            )])
+        };
+    }
+
+    let found = candidates(manifest_dir.clone(), cwd)
+        .map(|dir| dir.join(DIR_NAME))
+        .find(|dir| dir.is_dir());
+
+    found.ok_or_else(|| {
+        let mut searched: Vec<String> = manifest_dir
+            .iter()
+            .map(|dir| format!("{} (from CARGO_MANIFEST_DIR)", dir.join(DIR_NAME).display()))
+            .collect();
+
+        if let Some(cwd) = cwd {
+            searched.push(format!(
+                "\"{DIR_NAME}\" next to a {MARKER} in {} or one of its parents",
+                cwd.display()
+            ));
+        }
+
+        searched.push(format!(
+            "{} (where the template was built)",
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join(DIR_NAME)
+                .display()
+        ));
+        searched
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{candidates, discover, MARKER};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn discovers_data_dir() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let data = root.join("data");
+
+        // from a subdirectory of the crate, without the help of cargo.
+        let found = discover(None, None, Some(&root.join("src/template")));
+        assert_eq!(found, Ok(data.clone()));
+
+        let found = discover(None, Some(root.to_path_buf()), None);
+        assert_eq!(found, Ok(data.clone()));
+
+        let found = discover(Some(data.join("2023")), None, None);
+        assert_eq!(found, Ok(data.join("2023")));
+
+        let missing = PathBuf::from("/does/not/exist");
+        let searched = discover(Some(missing), Some(root.to_path_buf()), None).unwrap_err();
+        assert_eq!(searched, ["/does/not/exist (from AOC_DATA_DIR)"]);
+    }
+
+    #[test]
+    fn finds_root_from_subdirectory() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        let found = candidates(None, Some(&root.join("src/template")))
+            .find(|dir| dir.join(MARKER).is_file());
+        assert_eq!(found.as_deref(), Some(root));
+    }
+}
@@ -0,0 +1,248 @@
+/// Module for the error type shared by all template commands.
+/// Every failure class maps to its own exit code, so that scripts wrapping the CLI can tell them apart.
+use std::{
+    fmt::Display,
+    io,
+    process::{self, ExitStatus},
+};
+
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The command line could not be parsed.
+    Usage(String),
+    /// A file could not be read or written.
+    Io { path: String, source: io::Error },
+    /// The data directory was not found in any of the searched locations.
+    DataDirNotFound(Vec<String>),
+    /// A file does not have the expected content.
+    InvalidFile { path: String, reason: String },
+    /// An external command could not be started.
+    Spawn { command: String, source: io::Error },
+    /// An external command exited with a non-zero status.
+    Command { command: String, status: ExitStatus },
+    /// `AOC_BACKEND` names a backend that does not exist.
+    UnknownBackend(String),
+    /// The `aoc` command of the aoc-cli backend could not be found.
+    CliNotFound,
+    /// The http backend found no session cookie.
+    MissingSession,
+    /// The Advent of Code website answered a request with an error status.
+    Http { status: u16, url: String },
+    /// The Advent of Code website could not be reached.
+    Network(String),
+    /// A solution binary exited with a non-zero status.
+    SolutionFailed(ExitStatus),
+    /// A solution that runs in-process panicked.
+    Panicked,
+    /// Some days failed to run. Their errors have been printed already.
+    DaysFailed(usize),
+    /// Some answers did not match their accepted answer. The mismatches have been printed already.
+    WrongAnswers(usize),
+    /// Some parts got slower than their baseline. The regressions have been printed already.
+    Regressions(usize),
+    /// A solution part exceeded its time budget and was aborted.
+    TimedOut,
+    /// Adds the day that an error occurred for.
+    Day {
+        year: Year,
+        day: Day,
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns a function that adds the path to an [`io::Error`], for use with [`Result::map_err`].
+    pub fn io(path: impl Into<String>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| Error::Io { path, source }
+    }
+
+    /// The error of a solution binary that exited with a non-zero `status`.
+    /// A binary that was aborted because a part exceeded its time budget exits with the code of [`Error::TimedOut`].
+    #[must_use]
+    pub fn solution_failed(status: ExitStatus) -> Self {
+        if status.code() == Some(Error::TimedOut.exit_code().into()) {
+            Error::TimedOut
+        } else {
+            Error::SolutionFailed(status)
+        }
+    }
+
+    /// Adds the day that the error occurred for.
+    #[must_use]
+    pub fn for_day(self, year: Year, day: Day) -> Self {
+        Error::Day {
+            year,
+            day,
+            source: Box::new(self),
+        }
+    }
+
+    /// The exit code of the failure class:
+    ///
+    /// | Code | Failure |
+    /// | :---: | :--- |
+    /// | 1 | an answer did not match its accepted answer |
+    /// | 2 | invalid command-line arguments |
+    /// | 3 | a file could not be read or written, or has unexpected content |
+    /// | 4 | an external command could not be run or failed |
+    /// | 5 | the Advent of Code website could not be accessed |
+    /// | 6 | a solution failed |
+    /// | 7 | a solution got slower than its baseline |
+    /// | 8 | a solution exceeded its time budget |
+    #[must_use]
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::WrongAnswers(_) => 1,
+            Error::Usage(_) => 2,
+            Error::Io { .. } | Error::DataDirNotFound(_) | Error::InvalidFile { .. } => 3,
+            Error::Spawn { .. } | Error::Command { .. } => 4,
+            Error::UnknownBackend(_)
+            | Error::CliNotFound
+            | Error::MissingSession
+            | Error::Http { .. }
+            | Error::Network(_) => 5,
+            // the runner of a solution binary exits with these codes itself, see `runner::run_binary`.
+            // other codes, e.g. of a panic, could collide with the failure classes of the CLI.
+            Error::SolutionFailed(status) => match status.code() {
+                Some(2) => 2,
+                Some(3) => 3,
+                Some(5) => 5,
+                _ => 6,
+            },
+            Error::Panicked | Error::DaysFailed(_) => 6,
+            Error::Regressions(_) => 7,
+            Error::TimedOut => 8,
+            Error::Day { source, .. } => source.exit_code(),
+        }
+    }
+
+    /// Prints the error and exits with its exit code. Used where errors cannot be propagated to `main`.
+    pub fn exit(&self) -> ! {
+        eprintln!("Error: {self}");
+        process::exit(self.exit_code().into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Usage(message) => write!(f, "{message}"),
+            Error::Io { path, source } => write!(f, "could not access \"{path}\": {source}."),
+            Error::DataDirNotFound(searched) => write!(
+                f,
+                "could not find the data directory. Searched {}. Set AOC_DATA_DIR to its location.",
+                searched.join(", ")
+            ),
+            Error::InvalidFile { path, reason } => write!(f, "invalid file \"{path}\": {reason}"),
+            Error::Spawn { command, source } => write!(f, "could not run `{command}`: {source}."),
+            Error::Command { command, status } => write!(f, "`{command}` failed with {status}."),
+            Error::UnknownBackend(name) => write!(
+                f,
+                "unknown backend \"{name}\" in AOC_BACKEND. Valid values are \"aoc-cli\" and \"http\"."
+            ),
+            Error::CliNotFound => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or paste it into an \".adventofcode.session\" file in your home directory."
+            ),
+            Error::Http { status, url } => {
+                write!(f, "request to {url} failed with status {status}")?;
+                match status {
+                    400 | 401 | 403 => write!(f, ", the session cookie might have expired."),
+                    404 => write!(f, ", the puzzle might not be unlocked yet."),
+                    _ => write!(f, "."),
+                }
+            }
+            Error::Network(e) => write!(f, "could not reach the Advent of Code website: {e}"),
+            Error::SolutionFailed(status) => match status.code() {
+                Some(code) => write!(f, "the solution failed with exit code {code}."),
+                None => write!(f, "the solution failed with {status}."),
+            },
+            Error::Panicked => write!(f, "the solution panicked."),
+            Error::DaysFailed(1) => write!(f, "1 day failed to run."),
+            Error::DaysFailed(count) => write!(f, "{count} days failed to run."),
+            Error::WrongAnswers(1) => write!(f, "1 answer did not match."),
+            Error::WrongAnswers(count) => write!(f, "{count} answers did not match."),
+            Error::Regressions(1) => write!(f, "1 part got slower than its baseline."),
+            Error::Regressions(count) => write!(f, "{count} parts got slower than their baseline."),
+            Error::TimedOut => write!(f, "the solution exceeded its time budget."),
+            Error::Day { year, day, source } => write!(f, "{year} day {day}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } | Error::Spawn { source, .. } => Some(source),
+            Error::Day { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Error;
+    use crate::{day, year};
+    use std::io;
+
+    #[test]
+    fn day_context_keeps_exit_code() {
+        let error = Error::io("data/2023/inputs/05.txt")(io::ErrorKind::NotFound.into())
+            .for_day(year!(2023), day!(5));
+
+        assert_eq!(error.exit_code(), 3);
+        assert_eq!(
+            error.to_string(),
+            "2023 day 05: could not access \"data/2023/inputs/05.txt\": entity not found."
+        );
+    }
+
+    #[test]
+    fn failure_classes_have_distinct_exit_codes() {
+        let codes = [
+            Error::WrongAnswers(1).exit_code(),
+            Error::Usage(String::new()).exit_code(),
+            Error::InvalidFile {
+                path: "README.md".into(),
+                reason: String::new(),
+            }
+            .exit_code(),
+            Error::Spawn {
+                command: "cargo".into(),
+                source: io::ErrorKind::NotFound.into(),
+            }
+            .exit_code(),
+            Error::MissingSession.exit_code(),
+            Error::Panicked.exit_code(),
+            Error::Regressions(1).exit_code(),
+            Error::TimedOut.exit_code(),
+        ];
+        assert_eq!(codes, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn solution_failures_keep_only_runner_exit_codes() {
+        use std::os::unix::process::ExitStatusExt;
+        use std::process::ExitStatus;
+
+        let failed = |code: i32| Error::solution_failed(ExitStatus::from_raw(code << 8));
+
+        assert_eq!(failed(3).exit_code(), 3);
+        assert!(matches!(failed(8), Error::TimedOut));
+        assert_eq!(failed(1).exit_code(), 6);
+        assert_eq!(failed(101).exit_code(), 6);
+        assert_eq!(
+            failed(101).to_string(),
+            "the solution failed with exit code 101."
+        );
+    }
+}
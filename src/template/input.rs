@@ -0,0 +1,143 @@
+/// Module that normalizes puzzle inputs and splits them, so that solutions do not depend on the platform or on how git checked out a file.
+use std::{borrow::Borrow, ops::Deref};
+
+/// Normalizes the text of an input: `\r\n` line endings become `\n`, and trailing newlines are removed.
+/// Inputs are normalized when they are loaded, so solutions and their tests see the same text on every platform.
+#[must_use]
+pub fn normalize(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// A puzzle input with helpers to split it, which the parts and parse functions of every solution take.
+/// Dereferences to the text of the input. It relates to [`InputBuf`] like [`str`] to [`String`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Input(str);
+
+impl Input {
+    #[must_use]
+    pub fn new(text: &str) -> &Self {
+        // SAFETY: `Input` is a `repr(transparent)` wrapper around `str`, so both have the same layout.
+        unsafe { &*(text as *const str as *const Input) }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The lines of the input, without their line endings.
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.0.lines()
+    }
+
+    /// The blocks of the input that are separated by blank lines, without their trailing line endings.
+    /// Several blank lines in a row do not yield empty blocks.
+    pub fn paragraphs(&self) -> impl Iterator<Item = &str> {
+        let text = &self.0;
+        let mut paragraphs = vec![];
+        let (mut start, mut offset) = (0, 0);
+
+        for line in text.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                paragraphs.push(&text[start..offset]);
+                start = offset + line.len();
+            }
+            offset += line.len();
+        }
+        paragraphs.push(&text[start..]);
+
+        paragraphs
+            .into_iter()
+            .map(|paragraph| paragraph.trim_end_matches(['\r', '\n']))
+            .filter(|paragraph| !paragraph.is_empty())
+    }
+}
+
+impl<'a> From<&'a str> for &'a Input {
+    fn from(text: &'a str) -> Self {
+        Input::new(text)
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// An owned [`Input`], e.g. an example that was read by [`read_file`](crate::template::read_file).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct InputBuf(String);
+
+impl InputBuf {
+    #[must_use]
+    pub fn as_input(&self) -> &Input {
+        Input::new(&self.0)
+    }
+}
+
+impl From<String> for InputBuf {
+    fn from(text: String) -> Self {
+        Self(text)
+    }
+}
+
+impl Deref for InputBuf {
+    type Target = Input;
+
+    fn deref(&self) -> &Input {
+        self.as_input()
+    }
+}
+
+impl Borrow<Input> for InputBuf {
+    fn borrow(&self) -> &Input {
+        self.as_input()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{normalize, Input, InputBuf};
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc");
+        assert_eq!(normalize("a\nb\n\n"), "a\nb");
+        assert_eq!(normalize("a  \n"), "a  ");
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        let input = Input::new("seeds: 1 2\n\na:\n1\n2\n\n\nb:\n3\n");
+        assert_eq!(
+            input.paragraphs().collect::<Vec<_>>(),
+            ["seeds: 1 2", "a:\n1\n2", "b:\n3"]
+        );
+
+        let input = Input::new("a\r\nb\r\n\r\nc");
+        assert_eq!(input.paragraphs().collect::<Vec<_>>(), ["a\r\nb", "c"]);
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["a", "b", "", "c"]);
+        assert_eq!(Input::new("").paragraphs().count(), 0);
+    }
+
+    #[test]
+    fn owned_input_dereferences_to_input() {
+        let owned = InputBuf::from("a\n\nb".to_string());
+        let input: &Input = &owned;
+        assert_eq!(input, Input::new("a\n\nb"));
+        assert_eq!(owned.paragraphs().count(), 2);
+        assert_eq!(owned.len(), 4);
+    }
+}
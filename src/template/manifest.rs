@@ -0,0 +1,195 @@
+/// Module that keeps an index of the scaffolded days of a year in `data/<year>/manifest.txt`.
+/// Each line holds the progress of one day, e.g. `20: part-one`.
+/// Commands read the index to decide which days exist and which parts are still open.
+use std::{collections::BTreeMap, fmt::Display, fs, str::FromStr};
+
+use crate::template::{create_parent_dir, data_dir, Error};
+use crate::{Day, Year};
+
+/// The progress of a scaffolded day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayStatus {
+    NotStarted,
+    PartOneSolved,
+    Solved,
+}
+
+impl DayStatus {
+    /// Whether a part of the day still has to be solved. Parts beyond part two are never open.
+    #[must_use]
+    pub fn is_open(self, part: u8) -> bool {
+        match self {
+            DayStatus::NotStarted => part == 1 || part == 2,
+            DayStatus::PartOneSolved => part == 2,
+            DayStatus::Solved => false,
+        }
+    }
+
+    /// The status after a part has been solved. Solving part two implies that part one was solved before.
+    #[must_use]
+    pub fn with_solved(self, part: u8) -> Self {
+        let solved = match part {
+            1 => DayStatus::PartOneSolved,
+            2 => DayStatus::Solved,
+            _ => self,
+        };
+        self.max(solved)
+    }
+
+    /// The token that represents the status in the manifest.
+    fn token(self) -> &'static str {
+        match self {
+            DayStatus::NotStarted => "not-started",
+            DayStatus::PartOneSolved => "part-one",
+            DayStatus::Solved => "solved",
+        }
+    }
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DayStatus::NotStarted => "not started",
+            DayStatus::PartOneSolved => "part one solved",
+            DayStatus::Solved => "both solved",
+        })
+    }
+}
+
+impl FromStr for DayStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "not-started" => Ok(DayStatus::NotStarted),
+            "part-one" => Ok(DayStatus::PartOneSolved),
+            "solved" => Ok(DayStatus::Solved),
+            _ => Err(()),
+        }
+    }
+}
+
+#[must_use]
+pub fn get_manifest_path(year: Year) -> String {
+    data_dir::path(format!("{year}/manifest.txt"))
+}
+
+/// The module of a scaffolded day, in the root of the project, see [`data_dir::root`].
+#[must_use]
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    data_dir::project_path(format!("src/bin/{year}-{day}.rs"))
+}
+
+/// Reads the scaffolded days of a year and their progress. A missing manifest yields no days.
+pub fn read(year: Year) -> Result<BTreeMap<Day, DayStatus>, Error> {
+    let path = get_manifest_path(year);
+    match fs::read_to_string(&path) {
+        Ok(content) => parse(&content).map_err(|reason| Error::InvalidFile { path, reason }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(Error::io(path)(e)),
+    }
+}
+
+/// Returns the progress of a day, or [`None`] if it has not been scaffolded.
+/// An unreadable manifest is treated like a missing one.
+#[must_use]
+pub fn status(year: Year, day: Day) -> Option<DayStatus> {
+    read(year).ok()?.remove(&day)
+}
+
+/// Adds a newly scaffolded day. The progress of days that are in the manifest already is kept.
+pub fn add(year: Year, day: Day) -> Result<(), Error> {
+    let mut days = read(year)?;
+    days.entry(day).or_insert(DayStatus::NotStarted);
+    write(year, &days)
+}
+
+/// Records that a part of a day has been solved.
+pub fn mark_solved(year: Year, day: Day, part: u8) -> Result<(), Error> {
+    let mut days = read(year)?;
+    let status = days.entry(day).or_insert(DayStatus::NotStarted);
+    *status = status.with_solved(part);
+    write(year, &days)
+}
+
+/// Returns the years that have a manifest, in ascending order.
+#[must_use]
+pub fn years() -> Vec<Year> {
+    let mut years: Vec<Year> = fs::read_dir(data_dir::path(""))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let year = entry.file_name().to_str()?.parse().ok()?;
+            entry.path().join("manifest.txt").exists().then_some(year)
+        })
+        .collect();
+
+    years.sort_unstable();
+    years
+}
+
+fn write(year: Year, days: &BTreeMap<Day, DayStatus>) -> Result<(), Error> {
+    let path = get_manifest_path(year);
+    create_parent_dir(&path)?;
+    fs::write(&path, format_manifest(days)).map_err(Error::io(path))
+}
+
+fn parse(content: &str) -> Result<BTreeMap<Day, DayStatus>, String> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let invalid = || format!("invalid line \"{line}\", expected e.g. \"01: solved\".");
+            let (day, status) = line.split_once(':').ok_or_else(invalid)?;
+            let day = day.trim().parse().map_err(|_| invalid())?;
+            let status = status.trim().parse().map_err(|()| invalid())?;
+            Ok((day, status))
+        })
+        .collect()
+}
+
+fn format_manifest(days: &BTreeMap<Day, DayStatus>) -> String {
+    days.iter()
+        .map(|(day, status)| format!("{day}: {}\n", status.token()))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_manifest, parse, DayStatus};
+    use crate::day;
+
+    #[test]
+    fn parses_manifest() {
+        let days = parse("01: solved\n\n20: part-one\n 25 : not-started\n").unwrap();
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[&day!(1)], DayStatus::Solved);
+        assert_eq!(days[&day!(20)], DayStatus::PartOneSolved);
+        assert_eq!(days[&day!(25)], DayStatus::NotStarted);
+        assert_eq!(
+            format_manifest(&days),
+            "01: solved\n20: part-one\n25: not-started\n"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        assert!(parse("01: done\n").is_err());
+        assert!(parse("26: solved\n").is_err());
+        assert!(parse("solved\n").is_err());
+    }
+
+    #[test]
+    fn tracks_open_parts() {
+        let status = DayStatus::NotStarted;
+        assert!(status.is_open(1) && status.is_open(2) && !status.is_open(3));
+
+        let status = status.with_solved(1);
+        assert_eq!(status, DayStatus::PartOneSolved);
+        assert!(!status.is_open(1) && status.is_open(2));
+
+        assert_eq!(status.with_solved(2), DayStatus::Solved);
+        assert_eq!(DayStatus::Solved.with_solved(1), DayStatus::Solved);
+    }
+}
@@ -0,0 +1,275 @@
+//! Conversion of pages from the Advent of Code website to markdown.
+//! Only the handful of elements that puzzle descriptions are made of is supported, everything else is reduced to its text.
+
+/// Extracts the puzzle description from a page and converts it to markdown.
+/// Pages without a description, e.g. the response to a submission, are converted from their `<main>` element.
+#[must_use]
+pub fn from_html(html: &str) -> String {
+    let articles = elements(html, "article");
+
+    if articles.is_empty() {
+        let main = elements(html, "main");
+        convert(main.first().copied().unwrap_or(html))
+    } else {
+        convert(&articles.join("\n"))
+    }
+}
+
+/// Returns the contents of all elements with the given name. Elements of the same name must not be nested.
+fn elements<'a>(html: &'a str, name: &str) -> Vec<&'a str> {
+    let open = format!("<{name}");
+    let close = format!("</{name}>");
+    let mut found = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+
+        // skip elements that merely share a prefix, e.g. `<mainframe>`.
+        if !rest.starts_with(|c: char| c == '>' || c.is_ascii_whitespace()) {
+            continue;
+        }
+
+        let Some(content_start) = rest.find('>') else {
+            break;
+        };
+        rest = &rest[content_start + 1..];
+
+        let Some(end) = rest.find(&close) else {
+            break;
+        };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+
+    found
+}
+
+fn convert(html: &str) -> String {
+    let mut md = String::new();
+    // the target of every open link, if it has one.
+    let mut links: Vec<Option<String>> = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut md, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let (is_closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag
+            .split(|c: char| c.is_ascii_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => md.push_str("## "),
+            ("h2" | "p" | "ul", true) => end_block(&mut md),
+            ("pre", false) => {
+                in_pre = true;
+                md.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !md.ends_with('\n') {
+                    md.push('\n');
+                }
+                md.push_str("```");
+                end_block(&mut md);
+            }
+            ("code", _) if !in_pre => md.push('`'),
+            // emphasis is kept inside of code, as it marks the answers of examples.
+            ("em", _) if !in_pre => md.push('*'),
+            ("a", false) => {
+                let href = attribute(tag, "href");
+                if href.is_some() {
+                    md.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    md.push_str("](");
+                    md.push_str(&href);
+                    md.push(')');
+                }
+            }
+            ("li", false) => md.push_str("- "),
+            ("li" | "br", _) => md.push('\n'),
+            _ => {}
+        }
+    }
+
+    push_text(&mut md, rest, in_pre);
+
+    let mut md = md.trim().to_string();
+    md.push('\n');
+    md
+}
+
+/// Appends text, collapsing whitespace outside of preformatted blocks like a browser would.
+fn push_text(md: &mut String, text: &str, is_preformatted: bool) {
+    let text = decode_entities(text);
+
+    if is_preformatted {
+        md.push_str(&text);
+        return;
+    }
+
+    let at_line_start = md.is_empty() || md.ends_with('\n');
+    let mut words = text.split_ascii_whitespace().peekable();
+
+    if words.peek().is_none() {
+        if !at_line_start && !text.is_empty() && !md.ends_with(' ') {
+            md.push(' ');
+        }
+        return;
+    }
+
+    if !at_line_start && text.starts_with(|c: char| c.is_ascii_whitespace()) {
+        md.push(' ');
+    }
+
+    md.push_str(&words.collect::<Vec<_>>().join(" "));
+
+    if text.ends_with(|c: char| c.is_ascii_whitespace()) {
+        md.push(' ');
+    }
+}
+
+/// Ends a block element with an empty line.
+fn end_block(md: &mut String) {
+    while md.ends_with(' ') {
+        md.pop();
+    }
+    while !md.ends_with("\n\n") {
+        md.push('\n');
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, from_html};
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/support" target="_blank">global snow production</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul>
+<li>In <code>1abc2</code>, the value is <code><em>12</em></code>.</li>
+<li>Total: <code>142</code>.</li>
+</ul>
+<p>What is the sum of all of the calibration values?</p>
+</article>
+<p>Your puzzle answer was <code>54390</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Digits &amp; &lt;words&gt; &#x27;count&#39;.</p></article>
+</main></body></html>"#;
+
+        assert_eq!(
+            from_html(html),
+            "## --- Day 1: Trebuchet?! ---\n\
+             \n\
+             Something is *wrong* with [global snow production](/2023/support).\n\
+             \n\
+             For example:\n\
+             \n\
+             ```\n\
+             1abc2\n\
+             pqr3stu8vwx\n\
+             ```\n\
+             \n\
+             - In `1abc2`, the value is `*12*`.\n\
+             - Total: `142`.\n\
+             \n\
+             What is the sum of all of the calibration values?\n\
+             \n\
+             ## --- Part Two ---\n\
+             \n\
+             Digits & <words> 'count'.\n"
+        );
+    }
+
+    #[test]
+    fn converts_submission_response() {
+        let html = "<main>\n<article><p>That's not the right answer; your answer is too low.  <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+        assert_eq!(
+            from_html(html),
+            "That's not the right answer; your answer is too low. [[Return to Day 1]](/2023/day/1)\n"
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_entities() {
+        assert_eq!(
+            decode_entities("a & b &unknown; &#65;"),
+            "a & b &unknown; A"
+        );
+    }
+}
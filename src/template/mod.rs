@@ -1,36 +1,144 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Day, Year};
+use std::{fs, path::Path};
 
+pub mod answers;
+pub mod aoc_backend;
 pub mod aoc_cli;
+pub mod aoc_http;
 pub mod commands;
+pub mod data_dir;
+pub mod error;
+pub mod input;
+pub mod manifest;
+pub mod markdown;
+pub mod params;
+pub mod puzzle;
 pub mod readme_benchmarks;
+pub mod readme_stars;
 pub mod runner;
+pub mod solution;
+pub mod submissions;
+pub mod timings;
+
+pub use error::Error;
+pub use input::{Input, InputBuf};
+pub use params::Params;
+pub use solution::Solution;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// The path of the text file of a day in `data/<year>/<folder>`.
+#[must_use]
+pub fn get_data_path(folder: &str, year: Year, day: Day) -> String {
+    data_dir::path(format!("{year}/{folder}/{day}.txt"))
+}
+
+/// Creates the directory of a file in `data`, e.g. when a year is downloaded for the first time.
+pub fn create_parent_dir(path: &str) -> Result<(), Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir).map_err(Error::io(dir.to_string_lossy())),
+        None => Ok(()),
+    }
+}
+
+/// Reads a text file in `data/<year>/<folder>` to a string, with normalized line endings, see [`input::normalize`].
+/// The data directory is found independently of the working directory, see [`data_dir`].
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> Result<String, Error> {
+    let path = data_dir::find()?
+        .join(format!("{year}/{folder}/{day}.txt"))
+        .to_string_lossy()
+        .into_owned();
+    fs::read_to_string(&path)
+        .map(|text| input::normalize(&text))
+        .map_err(Error::io(path))
+}
+
+/// Helper function that reads a text file in `data/<year>/<folder>` to an [`Input`] that can be passed to the parts of a solution.
+/// Meant for tests, see [`try_read_file`] otherwise.
+///
+/// # Panics
+/// If the file cannot be read.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: Year, day: Day) -> InputBuf {
+    try_read_file(folder, year, day)
+        .map(InputBuf::from)
+        .unwrap_or_else(|e| panic!("{e}"))
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// This is a thin adapter on top of [`Solution`]: it generates a `solution` function that returns
+/// the day's `part_one` and `part_two` as a [`Solution`], and a `main` that runs it.
+///
+/// The year comes first, e.g. `solution!(2023, 1)`.
+/// An optional parse function can be passed as third argument, e.g. `solution!(2023, 17, parse)`.
+/// Its result is then shared by both parts, which take a reference to it instead of the raw input,
+/// and its execution time is reported separately.
+/// Alternatively, a type implementing [`Params`] can be passed, e.g. `solution!(2023, 21, params: Steps)`.
+/// Both parts then take the raw input and a reference to the parameters of the run.
+/// Both can be combined, e.g. `solution!(2023, 24, parse, params: TestArea)`, in which case the parts take
+/// a reference to the parsed value and to the parameters.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@main $year:expr, $day:expr, $solution:expr) => {
+        /// The year of the current day.
+        pub const YEAR: advent_of_code::Year = advent_of_code::year!($year);
+
         /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        pub const DAY: advent_of_code::Day = advent_of_code::day!($day);
+
+        /// Returns the solution for the current day.
+        pub fn solution() -> impl advent_of_code::template::Solution {
+            $solution
+        }
 
+        // unused when the solution is compiled into the CLI.
+        #[allow(dead_code)]
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            advent_of_code::template::runner::run_binary(&solution());
         }
     };
+    ($year:literal, $day:literal) => {
+        advent_of_code::solution!(
+            @main $year, $day,
+            advent_of_code::template::solution::FnSolution::new(YEAR, DAY, part_one, part_two)
+        );
+    };
+    ($year:literal, $day:literal, params: $params:ty) => {
+        advent_of_code::solution!(
+            @main $year, $day,
+            advent_of_code::template::solution::ParamFnSolution::<$params, _, _, _, _>::new(
+                YEAR,
+                DAY,
+                part_one,
+                part_two,
+            )
+        );
+    };
+    ($year:literal, $day:literal, $parse:expr, params: $params:ty) => {
+        advent_of_code::solution!(
+            @main $year, $day,
+            advent_of_code::template::solution::ParsedParamFnSolution::<$params, _, _, _, _, _, _>::new(
+                YEAR,
+                DAY,
+                |input: &advent_of_code::template::Input| ($parse)(input),
+                part_one,
+                part_two,
+            )
+        );
+    };
+    ($year:literal, $day:literal, $parse:expr) => {
+        advent_of_code::solution!(
+            @main $year, $day,
+            advent_of_code::template::solution::ParsedFnSolution::new(
+                YEAR,
+                DAY,
+                |input: &advent_of_code::template::Input| ($parse)(input),
+                part_one,
+                part_two,
+            )
+        );
+    };
 }
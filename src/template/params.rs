@@ -0,0 +1,92 @@
+/// Module for parameters that differ between the examples and the puzzle input, e.g. the number of steps to simulate.
+/// The runner passes the example defaults with `--example` and the real defaults otherwise.
+/// Single parameters can be overridden from the command line, e.g. `cargo solve 21 --param steps=500`.
+use std::{fmt::Display, str::FromStr};
+
+/// The parameters of a solution.
+pub trait Params {
+    /// The parameters that the examples of the puzzle description use.
+    fn example() -> Self;
+
+    /// The parameters of the puzzle input.
+    fn real() -> Self;
+
+    /// Overrides the parameter `name` with a value passed via `--param name=value`.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+/// Solutions without parameters.
+impl Params for () {
+    fn example() -> Self {}
+
+    fn real() -> Self {}
+
+    fn set(&mut self, _name: &str, _value: &str) -> Result<(), String> {
+        Err("the solution has no parameters.".into())
+    }
+}
+
+/// Parses the value of a parameter, for implementations of [`Params::set`].
+pub fn parse_value<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| format!("invalid value \"{value}\": {e}."))
+}
+
+/// The error of [`Params::set`] for a parameter that does not exist.
+#[must_use]
+pub fn unknown(name: &str, known: &[&str]) -> String {
+    format!(
+        "unknown parameter \"{name}\", expected one of: {}.",
+        known.join(", ")
+    )
+}
+
+/// The parameters of a solution in tests, with different defaults for the examples and the puzzle input.
+#[cfg(feature = "test_lib")]
+pub(crate) struct Steps(pub usize);
+
+#[cfg(feature = "test_lib")]
+impl Params for Steps {
+    fn example() -> Self {
+        Steps(6)
+    }
+
+    fn real() -> Self {
+        Steps(64)
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "steps" => self.0 = parse_value(value)?,
+            _ => return Err(unknown(name, &["steps"])),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Params, Steps};
+
+    #[test]
+    fn overrides_parameters() {
+        let mut steps = Steps::real();
+        steps.set("steps", "500").unwrap();
+        assert_eq!(steps.0, 500);
+
+        assert_eq!(
+            steps.set("steps", "x"),
+            Err("invalid value \"x\": invalid digit found in string.".into())
+        );
+        assert_eq!(
+            steps.set("step", "1"),
+            Err("unknown parameter \"step\", expected one of: steps.".into())
+        );
+        assert!(().set("steps", "1").is_err());
+    }
+}
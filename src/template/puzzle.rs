@@ -0,0 +1,201 @@
+//! Extraction of the title, example inputs and example answers from a downloaded puzzle description.
+//! Both the markdown written by aoc-cli and the one written by the built-in HTTP client are supported.
+
+/// The example of one part, as far as it could be found in the description.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    /// The example input, if the part introduces one.
+    pub input: Option<String>,
+    /// The answer for the example, which is the last emphasized code in the description of the part.
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part_one: Example,
+    /// [`None`] until part one has been solved and the description of part two was downloaded.
+    pub part_two: Option<Example>,
+}
+
+/// Returns the title of the puzzle, e.g. "Day 1: Trebuchet?!".
+#[must_use]
+pub fn title(puzzle: &str) -> Option<String> {
+    puzzle
+        .lines()
+        .filter_map(heading)
+        .find(|title| title.starts_with("Day "))
+}
+
+#[must_use]
+pub fn examples(puzzle: &str) -> Examples {
+    let lines: Vec<&str> = puzzle.lines().collect();
+    let part_two_start = lines
+        .iter()
+        .position(|line| heading(line).is_some_and(|title| title.starts_with("Part Two")));
+
+    match part_two_start {
+        None => Examples {
+            part_one: example(&lines, true),
+            part_two: None,
+        },
+        Some(start) => {
+            let part_one = example(&lines[..start], true);
+            let mut part_two = example(&lines[start..], false);
+            // a block that repeats the first example is not a new example.
+            if part_two.input == part_one.input {
+                part_two.input = None;
+            }
+            Examples {
+                part_one,
+                part_two: Some(part_two),
+            }
+        }
+    }
+}
+
+/// Returns the title of a heading like `## --- Part Two ---`. aoc-cli writes it without `##` and with escaped dashes.
+fn heading(line: &str) -> Option<String> {
+    let line = line.replace('\\', "");
+    let line = line.strip_prefix("## ").unwrap_or(&line).trim();
+    let title = line.strip_prefix("---")?.strip_suffix("---")?;
+    Some(title.trim().to_string())
+}
+
+/// Finds the example in the description of one part.
+/// The example is the first code block that follows a paragraph mentioning an example.
+/// As a fallback, the first code block is used for part one, which introduces the example in most puzzles.
+fn example(lines: &[&str], is_first_part: bool) -> Example {
+    let mut blocks = vec![];
+    let mut answer = None;
+    let mut paragraph = String::new();
+    let mut block: Option<String> = None;
+
+    for line in lines {
+        if line.starts_with("```") {
+            match block.take() {
+                Some(content) => blocks.push((paragraph.clone(), content)),
+                None => block = Some(String::new()),
+            }
+            continue;
+        }
+
+        if let Some(content) = block.as_mut() {
+            content.push_str(line);
+            content.push('\n');
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // the answers of solved parts are not part of the description.
+        if !line.starts_with("Your puzzle answer was") {
+            answer = last_emphasized_code(line).or(answer);
+        }
+        paragraph = (*line).to_string();
+    }
+
+    let mentions_example = |paragraph: &String| paragraph.to_lowercase().contains("example");
+    let input = blocks
+        .iter()
+        .find(|(paragraph, _)| mentions_example(paragraph))
+        .or(blocks.first().filter(|_| is_first_part))
+        .map(|(_, content)| content.clone());
+
+    Example { input, answer }
+}
+
+/// Returns the last code span that is emphasized, written as either `` `*42*` `` or `` *`42`* ``.
+fn last_emphasized_code(line: &str) -> Option<String> {
+    let mut found = None;
+    let mut rest = line;
+
+    while let Some(start) = rest.find('`') {
+        let emphasized_outside = rest[..start].ends_with('*');
+        rest = &rest[start + 1..];
+
+        let Some(end) = rest.find('`') else {
+            break;
+        };
+        let code = &rest[..end];
+        rest = &rest[end + 1..];
+
+        let emphasized_outside = emphasized_outside && rest.starts_with('*');
+        let emphasized_inside = code
+            .strip_prefix('*')
+            .and_then(|code| code.strip_suffix('*'));
+
+        match (emphasized_inside, emphasized_outside) {
+            (Some(code), _) if !code.is_empty() => found = Some(code.to_string()),
+            (None, true) if !code.is_empty() => found = Some(code.to_string()),
+            _ => {}
+        }
+    }
+
+    found
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{examples, last_emphasized_code, title, Example, Examples};
+
+    const PUZZLE: &str = include_str!("fixtures/puzzle.md");
+    const PUZZLE_AOC_CLI: &str = include_str!("fixtures/puzzle_aoc_cli.md");
+
+    #[test]
+    fn reads_title() {
+        assert_eq!(title(PUZZLE).as_deref(), Some("Day 1: Calibration"));
+        assert_eq!(title(PUZZLE_AOC_CLI).as_deref(), Some("Day 6: Signal Lock"));
+        assert_eq!(title("no heading"), None);
+    }
+
+    #[test]
+    fn finds_examples_of_both_parts() {
+        assert_eq!(
+            examples(PUZZLE),
+            Examples {
+                part_one: Example {
+                    input: Some("1abc2\npqr3stu8vwx\n".into()),
+                    answer: Some("50".into()),
+                },
+                part_two: Some(Example {
+                    input: Some("two1nine\neightwothree\n".into()),
+                    answer: Some("112".into()),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn finds_example_of_aoc_cli_puzzle() {
+        assert_eq!(
+            examples(PUZZLE_AOC_CLI),
+            Examples {
+                part_one: Example {
+                    input: Some("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".into()),
+                    answer: Some("7".into()),
+                },
+                part_two: Some(Example {
+                    input: None,
+                    answer: Some("19".into()),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn finds_example_without_part_two() {
+        let puzzle = PUZZLE.split("## --- Part Two ---").next().unwrap();
+        assert_eq!(examples(puzzle).part_two, None);
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            last_emphasized_code("values `12` and `*38*`, total *`50`*.").as_deref(),
+            Some("50")
+        );
+        assert_eq!(last_emphasized_code("just `code` and *emphasis*"), None);
+    }
+}
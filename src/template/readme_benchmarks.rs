@@ -1,186 +1,583 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{env, fs, time::Duration};
 
-use crate::Day;
+use crate::template::{data_dir, Error};
+use crate::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
-
-#[derive(Debug)]
-pub enum Error {
-    Parser(String),
-    IO(io::Error),
+/// Each year has its own table, delimited by a pair of these markers.
+fn marker(year: Year) -> String {
+    format!("<!--- benchmarking table {year} --->")
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
+/// The timing of one part of a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PartTiming {
+    Solved(Duration),
+    /// The part returned [`None`] and is not solved yet according to the manifest.
+    Open,
+    /// The part exceeded its time budget, which it is listed with.
+    TimedOut(Duration),
+    /// The part returned [`None`].
+    #[default]
+    Unsolved,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Timing of the parse step, for solutions that have a distinct one.
+    pub parse: Option<Duration>,
+    pub part_1: PartTiming,
+    pub part_2: PartTiming,
+    /// The fewest samples that any step of the day was measured with.
+    pub samples: Option<u128>,
     pub total_nanos: f64,
 }
 
+impl Timings {
+    /// The timings of a day before any of its parts were added.
+    #[must_use]
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            parse: None,
+            part_1: PartTiming::Unsolved,
+            part_2: PartTiming::Unsolved,
+            samples: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Adds the timing of a part, `0` for the parse step. Only solved parts count towards the total and the samples.
+    pub fn add(&mut self, part: u8, timing: PartTiming, samples: Option<u128>) {
+        match (part, timing) {
+            (0, PartTiming::Solved(duration)) => self.parse = Some(duration),
+            (1, _) => self.part_1 = timing,
+            (2, _) => self.part_2 = timing,
+            _ => {}
+        }
+
+        if let PartTiming::Solved(duration) = timing {
+            self.samples = match (self.samples, samples) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+
+            #[allow(clippy::cast_precision_loss)]
+            {
+                self.total_nanos += duration.as_nanos() as f64;
+            }
+        }
+    }
+}
+
+/// The layout of the benchmark tables, configured via environment variables in `.cargo/config.toml`:
+/// - `AOC_BENCHMARK_HEADING`: the markdown prefix of the heading, `##` by default. An empty value leaves out the heading.
+/// - `AOC_BENCHMARK_COLUMNS`: the optional columns, a comma-separated list of `total`, `share` and `samples`. All of them by default.
+/// - `AOC_BENCHMARK_SLOWEST`: the number of days listed in a section of the slowest days, which is left out by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableLayout {
+    pub heading: String,
+    pub total: bool,
+    pub share: bool,
+    pub samples: bool,
+    pub slowest: usize,
+}
+
+impl Default for TableLayout {
+    fn default() -> Self {
+        Self {
+            heading: "##".into(),
+            total: true,
+            share: true,
+            samples: true,
+            slowest: 0,
+        }
+    }
+}
+
+impl TableLayout {
+    pub fn from_env() -> Result<Self, Error> {
+        let mut layout = Self::default();
+
+        if let Ok(heading) = env::var("AOC_BENCHMARK_HEADING") {
+            layout.heading = heading.trim().to_string();
+        }
+
+        if let Ok(columns) = env::var("AOC_BENCHMARK_COLUMNS") {
+            layout.total = false;
+            layout.share = false;
+            layout.samples = false;
+
+            for column in columns.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+                match column {
+                    "total" => layout.total = true,
+                    "share" => layout.share = true,
+                    "samples" => layout.samples = true,
+                    _ => {
+                        return Err(Error::Usage(format!(
+                            "unknown column \"{column}\" in AOC_BENCHMARK_COLUMNS. Valid values are \"total\", \"share\" and \"samples\"."
+                        )))
+                    }
+                }
+            }
+        }
+
+        if let Ok(slowest) = env::var("AOC_BENCHMARK_SLOWEST") {
+            layout.slowest = slowest.trim().parse().map_err(|_| {
+                Error::Usage(format!(
+                    "AOC_BENCHMARK_SLOWEST should be a number of days, got \"{slowest}\"."
+                ))
+            })?;
+        }
+
+        Ok(layout)
+    }
+
+    /// The heading of a section, one level below the heading of the table.
+    fn subheading(&self, title: &str) -> String {
+        match self.heading.as_str() {
+            "" => format!("**{title}**"),
+            heading => format!("{heading}# {title}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(invalid_readme(&format!(
+            "too many occurences of \"{marker}\"."
+        )));
     }
 
     let pos_start = matches
         .first()
         .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+        .ok_or_else(|| invalid_readme(&format!("could not find \"{marker}\".")))?;
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+    let pos_end = matches.last().map_or(pos_start, |m| m.0 + m.1.len());
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn invalid_readme(reason: &str) -> Error {
+    Error::InvalidFile {
+        path: readme_path(),
+        reason: reason.into(),
+    }
+}
+
+/// Replaces the table between a pair of markers, or a single marker when the table is written for the first time.
+/// The new table has to include the markers itself.
+pub(crate) fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+/// Reads the readme, applies `update` to its content and writes it back.
+pub(crate) fn update_readme(
+    update: impl FnOnce(&mut String) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let path = readme_path();
+    let readme = fs::read(&path).map_err(Error::io(&path))?;
+    let mut readme = String::from_utf8_lossy(&readme).to_string();
+    update(&mut readme)?;
+    fs::write(&path, &readme).map_err(Error::io(path))
+}
+
+/// The readme in the root of the project, see [`data_dir::root`].
+fn readme_path() -> String {
+    data_dir::project_path("README.md")
+}
+
+/// Formats a duration with three significant digits in the largest unit below it, e.g. `1.25 ms` or `980 µs`.
+#[allow(clippy::cast_precision_loss)]
+fn format_duration(nanos: f64) -> String {
+    let (value, unit) = match nanos {
+        n if n >= 1e9 => (n / 1e9, "s"),
+        n if n >= 1e6 => (n / 1e6, "ms"),
+        n if n >= 1e3 => (n / 1e3, "µs"),
+        n => (n, "ns"),
+    };
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+    let decimals = match value {
+        _ if unit == "ns" => 0,
+        v if v >= 100.0 => 0,
+        v if v >= 10.0 => 1,
+        _ => 2,
+    };
+    format!("{value:.decimals$} {unit}")
+}
+
+fn format_part(timing: PartTiming) -> String {
+    match timing {
+        #[allow(clippy::cast_precision_loss)]
+        PartTiming::Solved(duration) => {
+            format!("`{}`", format_duration(duration.as_nanos() as f64))
+        }
+        #[allow(clippy::cast_precision_loss)]
+        PartTiming::TimedOut(budget) => {
+            format!(
+                "timed out (`{}`)",
+                format_duration(budget.as_nanos() as f64)
+            )
+        }
+        PartTiming::Open => "open".into(),
+        PartTiming::Unsolved => "✖".into(),
+    }
+}
+
+fn format_share(nanos: f64, total_nanos: f64) -> String {
+    if total_nanos > 0.0 {
+        format!("{:.1}%", nanos / total_nanos * 100.0)
+    } else {
+        "-".into()
+    }
+}
+
+fn table_row(cells: &[String]) -> String {
+    format!("| {} |", cells.join(" | "))
+}
+
+fn construct_table(
+    year: Year,
+    mut timings: Vec<Timings>,
+    total_millis: f64,
+    layout: &TableLayout,
+) -> String {
+    let has_parse = timings.iter().any(|timing| timing.parse.is_some());
+    let total_nanos = total_millis * 1e6;
+    // links are relative to the readme, wherever the command runs.
+    let link = |day: Day| format!("[Day {}](./src/bin/{year}-{day}.rs)", day.into_inner());
+
+    let mut lines: Vec<String> = vec![marker(year)];
+    if !layout.heading.is_empty() {
+        lines.push(format!("{} {year} Benchmarks", layout.heading));
+        lines.push(String::new());
+    }
+
+    let mut header = vec!["Day"];
+    if has_parse {
+        header.push("Parse");
+    }
+    header.extend(["Part 1", "Part 2"]);
+    let columns = [
+        (layout.total, "Total"),
+        (layout.share, "Share"),
+        (layout.samples, "Samples"),
     ];
+    header.extend(
+        columns
+            .iter()
+            .filter(|(shown, _)| *shown)
+            .map(|(_, name)| *name),
+    );
 
-    for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    lines.push(table_row(
+        &header.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    ));
+    lines.push(table_row(&vec![":---:".to_string(); header.len()]));
+
+    for timing in &timings {
+        let mut cells = vec![link(timing.day)];
+        if has_parse {
+            #[allow(clippy::cast_precision_loss)]
+            cells.push(timing.parse.map_or_else(
+                || "-".into(),
+                |parse| format!("`{}`", format_duration(parse.as_nanos() as f64)),
+            ));
+        }
+        cells.push(format_part(timing.part_1));
+        cells.push(format_part(timing.part_2));
+        if layout.total {
+            cells.push(format!("`{}`", format_duration(timing.total_nanos)));
+        }
+        if layout.share {
+            cells.push(format_share(timing.total_nanos, total_nanos));
+        }
+        if layout.samples {
+            cells.push(
+                timing
+                    .samples
+                    .map_or_else(|| "-".into(), |samples| samples.to_string()),
+            );
+        }
+        lines.push(table_row(&cells));
     }
 
     lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(format!("**Total: {}**", format_duration(total_nanos)));
+
+    if layout.slowest > 0 {
+        timings.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+
+        lines.push(String::new());
+        lines.push(layout.subheading("Slowest days"));
+        lines.push(String::new());
+        lines.push("| Day | Total | Share |".into());
+        lines.push("| :---: | :---: | :---: |".into());
+        for timing in timings.iter().take(layout.slowest) {
+            lines.push(table_row(&[
+                link(timing.day),
+                format!("`{}`", format_duration(timing.total_nanos)),
+                format_share(timing.total_nanos, total_nanos),
+            ]));
+        }
+    }
+
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    layout: &TableLayout,
+) -> Result<(), Error> {
+    let table = construct_table(year, timings, total_millis, layout);
+    replace_table(s, &marker(year), &table)
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
-    Ok(())
+/// Replaces the benchmarks of a year in the readme. The readme needs to contain the marker of that year.
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+    let layout = TableLayout::from_env()?;
+    update_readme(|readme| update_content(readme, year, timings, total_millis, &layout))
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{format_duration, marker, update_content, PartTiming, TableLayout, Timings};
+    use crate::{day, year, Year};
+    use std::time::Duration;
+
+    fn timings(day: crate::Day, part_1: u64, part_2: u64) -> Timings {
+        Timings {
+            day,
+            parse: None,
+            part_1: PartTiming::Solved(Duration::from_millis(part_1)),
+            part_2: PartTiming::Solved(Duration::from_millis(part_2)),
+            samples: Some(10),
+            #[allow(clippy::cast_precision_loss)]
+            total_nanos: ((part_1 + part_2) * 1_000_000) as f64,
+        }
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
-            Timings {
-                day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
-                total_nanos: 3e+10,
-            },
-            Timings {
-                day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
-                total_nanos: 7e+10,
-            },
-            Timings {
-                day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
-                total_nanos: 9e+10,
-            },
+            timings(day!(1), 10, 20),
+            timings(day!(2), 30, 40),
+            timings(day!(4), 40, 50),
         ]
     }
 
+    fn update(s: &mut String, year: Year, timings: Vec<Timings>, layout: &TableLayout) {
+        update_content(s, year, timings, 190.0, layout).unwrap();
+    }
+
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("{0} {0} {0}", marker(year!(2023)));
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let mut s = format!("foo\nbar\n{0}{0}\nbaz", marker(year!(2023)));
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
+        assert_eq!(s.contains("## 2023 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let mut s = format!("foo\nbar\n{0}{0}\nbaz", marker(year!(2023)));
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
+        assert_eq!(
+            s.matches(&marker(year!(2023))).collect::<Vec<&str>>().len(),
+            2
+        );
+        assert_eq!(
+            s.matches("## 2023 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn leaves_other_years_untouched() {
+        let mut s = format!("{0}{0}\n{1}{1}", marker(year!(2022)), marker(year!(2023)));
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
+        assert!(s.starts_with(&format!("{0}{0}\n", marker(year!(2022)))));
+        assert!(!s.contains("## 2022 Benchmarks"));
+        assert!(s.contains("## 2023 Benchmarks"));
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("foo\nbar\n{0}\n{0}\nbaz", marker(year!(2023)));
+        update(
+            &mut s,
+            year!(2023),
+            get_mock_timings(),
+            &TableLayout::default(),
+        );
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
+            "",
+            "| Day | Part 1 | Part 2 | Total | Share | Samples |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10.0 ms` | `20.0 ms` | `30.0 ms` | 15.8% | 10 |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30.0 ms` | `40.0 ms` | `70.0 ms` | 36.8% | 10 |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40.0 ms` | `50.0 ms` | `90.0 ms` | 47.4% | 10 |",
+            "",
+            "**Total: 190 ms**",
+            "<!--- benchmarking table 2023 --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse_step() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some(Duration::from_millis(5));
+
+        let layout = TableLayout {
+            total: false,
+            share: false,
+            samples: false,
+            ..TableLayout::default()
+        };
+
+        let mut s = format!("foo\nbar\n{0}\n{0}\nbaz", marker(year!(2023)));
+        update(&mut s, year!(2023), timings, &layout);
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## 2023 Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2023-01.rs) | - | `10.0 ms` | `20.0 ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `5.00 ms` | `30.0 ms` | `40.0 ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | - | `40.0 ms` | `50.0 ms` |",
             "",
-            "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "**Total: 190 ms**",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_unsolved_parts() {
+        let mut timings = get_mock_timings();
+        timings[1].part_2 = PartTiming::Open;
+        timings[2].part_2 = PartTiming::Unsolved;
+        timings[2].part_1 = PartTiming::TimedOut(Duration::from_secs(10));
+
+        let layout = TableLayout {
+            samples: false,
+            ..TableLayout::default()
+        };
+
+        let mut s = format!("{0}\n{0}", marker(year!(2023)));
+        update(&mut s, year!(2023), timings, &layout);
+        assert!(
+            s.contains("| [Day 2](./src/bin/2023-02.rs) | `30.0 ms` | open | `70.0 ms` | 36.8% |")
+        );
+        assert!(s.contains(
+            "| [Day 4](./src/bin/2023-04.rs) | timed out (`10.0 s`) | ✖ | `90.0 ms` | 47.4% |"
+        ));
+    }
+
+    #[test]
+    fn format_slowest_days() {
+        let layout = TableLayout {
+            heading: "###".into(),
+            slowest: 2,
+            ..TableLayout::default()
+        };
+
+        let mut s = format!("{0}\n{0}", marker(year!(2023)));
+        update(&mut s, year!(2023), get_mock_timings(), &layout);
+        let slowest = [
+            "**Total: 190 ms**",
+            "",
+            "#### Slowest days",
+            "",
+            "| Day | Total | Share |",
+            "| :---: | :---: | :---: |",
+            "| [Day 4](./src/bin/2023-04.rs) | `90.0 ms` | 47.4% |",
+            "| [Day 2](./src/bin/2023-02.rs) | `70.0 ms` | 36.8% |",
+            "<!--- benchmarking table 2023 --->",
+        ]
+        .join("\n");
+        assert!(s.contains("### 2023 Benchmarks"));
+        assert!(s.ends_with(&slowest));
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(74.0), "74 ns");
+        assert_eq!(format_duration(1_250.0), "1.25 µs");
+        assert_eq!(format_duration(980_100.0), "980 µs");
+        assert_eq!(format_duration(74_130_000.0), "74.1 ms");
+        assert_eq!(format_duration(2.1e9), "2.10 s");
+    }
 }
@@ -0,0 +1,184 @@
+/// Module that fills the `advent_readme_stars` section of the readme with the stars of every year.
+/// The table has the same layout as the one written by the `advent-readme-stars` Github action,
+/// but is built from the local answer and submission records instead of the private leaderboard.
+use std::collections::BTreeMap;
+
+use crate::template::{
+    answers,
+    readme_benchmarks::{replace_table, update_readme},
+    submissions::{self, Submission, Verdict},
+    Error,
+};
+use crate::{all_days, Day, Year};
+
+const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The stars that were earned for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+impl Stars {
+    #[must_use]
+    pub fn count(&self) -> usize {
+        usize::from(self.part_1) + usize::from(self.part_2)
+    }
+}
+
+/// Collects the stars of every day of a year.
+/// A part earned its star if an accepted answer is stored in `data/<year>/answers`,
+/// or if the submission log contains a correct answer or a hint that the part was completed already.
+#[must_use]
+pub fn collect(year: Year) -> Vec<Stars> {
+    all_days()
+        .map(|day| {
+            stars_of_day(
+                day,
+                &answers::read(year, day),
+                &submissions::read(year, day),
+            )
+        })
+        .collect()
+}
+
+fn stars_of_day(day: Day, answers: &BTreeMap<u8, String>, submissions: &[Submission]) -> Stars {
+    let earned = |part: u8| {
+        answers.contains_key(&part)
+            || submissions.iter().any(|submission| {
+                submission.part == part
+                    && matches!(
+                        submission.verdict,
+                        Verdict::Correct | Verdict::AlreadyCompleted
+                    )
+            })
+    };
+
+    Stars {
+        day,
+        part_1: earned(1),
+        part_2: earned(2),
+    }
+}
+
+fn construct_table(years: &[(Year, Vec<Stars>)]) -> String {
+    let star = |earned: bool| if earned { "⭐" } else { " " };
+    let mut lines = vec![MARKER.to_string()];
+
+    for (year, stars) in years {
+        lines.push(format!("## {year} Results"));
+        lines.push(String::new());
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: |".into());
+
+        for stars in stars.iter().filter(|stars| stars.count() > 0) {
+            let day = stars.day.into_inner();
+            lines.push(format!(
+                "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+                star(stars.part_1),
+                star(stars.part_2)
+            ));
+        }
+
+        lines.push(String::new());
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, years: &[(Year, Vec<Stars>)]) -> Result<(), Error> {
+    replace_table(s, MARKER, &construct_table(years))
+}
+
+/// Replaces the star table in the readme with the stars of the given years, and returns the number of stars.
+/// The readme needs to contain the `advent_readme_stars` marker.
+pub fn update(years: &[Year]) -> Result<usize, Error> {
+    let stars: Vec<(Year, Vec<Stars>)> = years.iter().map(|&year| (year, collect(year))).collect();
+    let count = stars
+        .iter()
+        .flat_map(|(_, stars)| stars)
+        .map(Stars::count)
+        .sum();
+
+    update_readme(|readme| update_content(readme, &stars))?;
+    Ok(count)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{stars_of_day, update_content, Stars, MARKER};
+    use crate::template::submissions::{Submission, Verdict};
+    use crate::{day, year};
+    use std::collections::BTreeMap;
+
+    fn submission(part: u8, verdict: Verdict) -> Submission {
+        Submission {
+            timestamp: 0,
+            part,
+            answer: "42".into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn earns_stars_from_records() {
+        let answers = BTreeMap::from([(1, "42".to_string())]);
+        assert_eq!(stars_of_day(day!(1), &answers, &[]).count(), 1);
+
+        let submissions = [
+            submission(1, Verdict::TooLow),
+            submission(1, Verdict::Correct),
+            submission(2, Verdict::Incorrect),
+        ];
+        let stars = stars_of_day(day!(2), &BTreeMap::new(), &submissions);
+        assert!(stars.part_1 && !stars.part_2);
+
+        let submissions = [submission(2, Verdict::AlreadyCompleted)];
+        let stars = stars_of_day(day!(3), &BTreeMap::new(), &submissions);
+        assert!(!stars.part_1 && stars.part_2);
+    }
+
+    #[test]
+    fn writes_stars_of_started_days() {
+        let stars = vec![
+            Stars {
+                day: day!(1),
+                part_1: true,
+                part_2: true,
+            },
+            Stars {
+                day: day!(2),
+                part_1: false,
+                part_2: false,
+            },
+            Stars {
+                day: day!(20),
+                part_1: true,
+                part_2: false,
+            },
+        ];
+
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, &[(year!(2023), stars.clone())]).unwrap();
+        update_content(&mut s, &[(year!(2023), stars)]).unwrap();
+
+        let expected = [
+            "foo",
+            MARKER,
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 20](https://adventofcode.com/2023/day/20) | ⭐ |   |",
+            "",
+            MARKER,
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -1,63 +1,340 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::answers::{self, Verification};
+use crate::template::aoc_backend;
+use crate::template::manifest;
+use crate::template::submissions::{self, Verdict};
+use crate::template::{
+    get_data_path,
+    input::{normalize, Input},
+    params::Params,
+    solution::Solution,
+    try_read_file, Error, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{Day, Year};
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Write};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// How a part that exceeds its time budget is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnTimeout {
+    /// The part is reported as timed out as soon as it exceeds its budget, and the process ends.
+    /// The remaining parts of the day are skipped. Used by the solution binaries.
+    Exit,
+    /// Time budgets are not applied.
+    /// Solutions that run in-process share the process with the CLI, so they cannot be aborted.
+    Ignore,
+}
+
+/// How a solution is run. The solution binaries read it from their command line, see [`RunSettings::from_args`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunSettings {
+    /// Benches the parts and reports this statistic.
+    pub time: Option<Statistic>,
+    pub format: OutputFormat,
+    /// The budget of every part, unless the day has a budget of its own in `AOC_TIMEOUT_<year>_<day>`.
+    pub timeout: Option<TimeBudget>,
+    /// Runs only this part, besides the parse step.
+    pub part: Option<u8>,
+    pub input: InputSource,
+    /// Overrides of the parameters of the run, as `name=value`.
+    pub params: Vec<String>,
+    /// Submits the answer of this part.
+    pub submit: Option<u8>,
+}
+
+impl RunSettings {
+    /// Parses the command line of a solution binary.
+    pub fn from_args() -> Result<Self, Error> {
+        Self::parse(&env::args().collect::<Vec<_>>())
+    }
+
+    fn parse(args: &[String]) -> Result<Self, Error> {
+        Ok(Self {
+            time: time_statistic(args)?,
+            format: parse_option(args, "--format")?.unwrap_or_default(),
+            timeout: parse_option(args, "--timeout")?,
+            part: parse_option(args, "--part")?,
+            input: input_source(args)?,
+            params: option_values(args, "--param"),
+            submit: submit_part(args)?,
+        })
+    }
+}
+
+/// Parses the input once and runs every part of the solution against it, or only the part selected in `settings`.
+/// The parts get the parameters of the puzzle input, with the overrides in `settings`.
+/// Used for solutions that run in-process, so time budgets are not applied, see [`OnTimeout::Ignore`].
+pub fn run_solution<S: Solution>(
+    solution: &S,
+    input: &Input,
+    settings: &RunSettings,
+) -> Result<Vec<PartResult>, Error> {
+    let params = run_params(false, &settings.params)?;
+    Ok(run_parts(
+        solution,
+        input,
+        &params,
+        settings,
+        OnTimeout::Ignore,
+        |_| true,
+    ))
+}
+
+/// Runs a solution binary against the input selected on the command line, see [`InputSource`].
+/// With `--example`, the parts get the parameters of the examples, and part two runs against its own example if the puzzle has one.
+pub fn run_binary<S: Solution>(solution: &S) {
+    let (year, day) = (solution.year(), solution.day());
+    let read = |folder: &str| try_read_file(folder, year, day).unwrap_or_else(|e| e.exit());
+    let settings = RunSettings::from_args().unwrap_or_else(|e| e.exit());
+    let params = binary_params(&settings.input, &settings.params).unwrap_or_else(|e| e.exit());
+    let run = |input: &str, include: &dyn Fn(u8) -> bool| {
+        run_parts(
+            solution,
+            Input::new(input),
+            &params,
+            &settings,
+            OnTimeout::Exit,
+            include,
+        );
+    };
+
+    let input = match &settings.input {
+        InputSource::Puzzle => read("inputs"),
+        InputSource::Example if has_example_part2(year, day) => {
+            if settings.part != Some(2) {
+                run(&read("examples"), &|part| part != 2);
+            }
+            if settings.part.is_none_or(|part| part == 2) {
+                run(&read("examples_part2"), &|part| part == 2);
+            }
+            return;
+        }
+        InputSource::Example => read("examples"),
+        InputSource::File(path) => fs::read_to_string(path)
+            .map(|text| normalize(&text))
+            .unwrap_or_else(|e| Error::io(path)(e).exit()),
+        InputSource::Stdin => io::read_to_string(io::stdin())
+            .map(|text| normalize(&text))
+            .unwrap_or_else(|e| Error::io("<stdin>")(e).exit()),
+    };
+
+    run(&input, &|_| true);
+}
+
+/// Scaffolding creates empty example files, so only an example that is not empty counts.
+fn has_example_part2(year: Year, day: Day) -> bool {
+    fs::metadata(get_data_path("examples_part2", year, day))
+        .is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Runs the parts that are selected in `settings` and accepted by `include`.
+fn run_parts<S: Solution>(
+    solution: &S,
+    input: &Input,
+    params: &S::Params,
+    settings: &RunSettings,
+    on_timeout: OnTimeout,
+    include: impl Fn(u8) -> bool,
+) -> Vec<PartResult> {
+    let (year, day) = (solution.year(), solution.day());
+    let is_selected =
+        |part: u8| include(part) && settings.part.is_none_or(|selected| selected == part);
+    let mut results = vec![];
+    let input = if solution.has_parse_step() {
+        let (input, result) = run_parse(|input| solution.parse(input), input, year, day, settings);
+        results.push(result);
+        input
+    } else {
+        solution.parse(input)
+    };
+
+    if is_selected(1) {
+        results.push(run_part(
+            |input| solution.part_one(input, params),
+            &input,
+            year,
+            day,
+            1,
+            settings,
+            on_timeout,
+        ));
+    }
+
+    if is_selected(2) {
+        results.push(run_part(
+            |input| solution.part_two(input, params),
+            &input,
+            year,
+            day,
+            2,
+            settings,
+            on_timeout,
+        ));
+    }
+
+    for part in (3..3 + solution.extra_parts()).filter(|&part| is_selected(part)) {
+        results.push(run_part(
+            |input| solution.extra_part(part, input, params),
+            &input,
+            year,
+            day,
+            part,
+            settings,
+            on_timeout,
+        ));
+    }
+
+    results
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    settings: &RunSettings,
+    on_timeout: OnTimeout,
+) -> PartResult {
     let part_str = format!("Part {part}");
+    let statistic = settings.time;
+    let format = settings.format;
+    let budget = match on_timeout {
+        OnTimeout::Exit => time_budget(year, day, settings.timeout),
+        OnTimeout::Ignore => None,
+    };
+
+    let watchdog = budget
+        .map(|budget| Watchdog::start(PartResult::timed_out(year, day, part, budget), format));
+
+    let (result, stats) = run_timed(func, input, statistic.is_some(), budget, |result| {
+        if let Some(watchdog) = &watchdog {
+            watchdog.finish();
+        }
+
+        if format == OutputFormat::Text {
+            print_result(result, &part_str, "", "");
+            if statistic.is_some() {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
+
+    let mut part_result = PartResult::new(
+        year,
+        day,
+        part,
+        result.as_ref().map(ToString::to_string),
+        stats,
+        statistic.unwrap_or_default(),
+    );
+    // the accepted answers and submissions only apply to the puzzle input.
+    let is_puzzle_input = settings.input == InputSource::Puzzle;
+    if is_puzzle_input {
+        part_result.expected = answers::expected(year, day, part);
+    }
+    // the watchdog usually ends the process before this point. A part that finishes just after its budget,
+    // before the watchdog thread has woken up, passes it though, and is reported as timed out here.
+    let is_timed_out = budget.is_some_and(|budget| part_result.stats.min > budget);
+    if is_timed_out {
+        part_result.status = PartStatus::TimedOut;
+    } else if part_result.status == PartStatus::Unsolved
+        && manifest::status(year, day).is_some_and(|status| status.is_open(part))
+    {
+        part_result.status = PartStatus::Open;
+    }
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    match format {
+        OutputFormat::Text => print_part_result(&part_result),
+        OutputFormat::Json => println!("{}", part_result.to_json()),
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    // like the watchdog, skip the remaining parts.
+    if is_timed_out {
+        let _ = stdout().flush();
+        process::exit(Error::TimedOut.exit_code().into());
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(result) = result.filter(|_| is_puzzle_input && settings.submit == Some(part)) {
+        submit_result(result, year, day, part);
     }
+
+    part_result
+}
+
+/// Run the parse step of a solution. Its timings are reported like a part with number `0`.
+fn run_parse<'a, T>(
+    func: impl Fn(&'a Input) -> T,
+    input: &'a Input,
+    year: Year,
+    day: Day,
+    settings: &RunSettings,
+) -> (T, PartResult) {
+    let statistic = settings.time;
+    let format = settings.format;
+
+    let (parsed, stats) = run_timed(func, input, statistic.is_some(), None, |_| {
+        if format == OutputFormat::Text && statistic.is_some() {
+            print!("Parse: > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+    });
+
+    let result = PartResult::parse_step(year, day, stats, statistic.unwrap_or_default());
+
+    match format {
+        OutputFormat::Text => print_part_result(&result),
+        OutputFormat::Json => println!("{}", result.to_json()),
+    }
+
+    (parsed, result)
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// A function that took longer than its `budget` is not benched, see the watchdog in [`run_part`].
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
+    budget: Option<Duration>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let stats = if is_timed && budget.is_none_or(|budget| base_time <= budget) {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(&[base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Bench a solution part. A batch of warm-up runs is executed and discarded before the measured runs.
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let warmup_iterations = cmp::max(bench_iterations / 10, 1);
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    for _ in 0..warmup_iterations {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -69,19 +346,622 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Ends the process once a solution part exceeds its time budget, reporting the part as timed out.
+/// The process exits with the code of [`Error::TimedOut`], so that callers can tell a timeout from a failed solution.
+struct Watchdog {
+    finished: Sender<()>,
+}
+
+impl Watchdog {
+    fn start(timed_out: PartResult, format: OutputFormat) -> Self {
+        let (finished, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            // the part either finished in time, or the watchdog was dropped along with it.
+            if receiver.recv_timeout(timed_out.duration) != Err(RecvTimeoutError::Timeout) {
+                return;
+            }
+
+            match format {
+                OutputFormat::Text => print_part_result(&timed_out),
+                OutputFormat::Json => println!("{}", timed_out.to_json()),
+            }
+
+            let _ = stdout().flush();
+            process::exit(Error::TimedOut.exit_code().into());
+        });
+
+        Self { finished }
+    }
+
+    fn finish(&self) {
+        // the watchdog thread is gone if the part finished already.
+        let _ = self.finished.send(());
+    }
+}
+
+/// The time budget of a single execution of a solution part, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBudget(pub Duration);
+
+impl Display for TimeBudget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.as_secs_f64())
+    }
+}
+
+impl FromStr for TimeBudget {
+    type Err = TimeBudgetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse()
+            .ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .filter(|duration| !duration.is_zero())
+            .map(TimeBudget)
+            .ok_or(TimeBudgetFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`TimeBudget`].
+#[derive(Debug)]
+pub struct TimeBudgetFromStrError;
+
+impl std::error::Error for TimeBudgetFromStrError {}
+
+impl Display for TimeBudgetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a positive number of seconds")
+    }
+}
+
+/// A statistic that can be reported for a benched solution part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Statistic {
+    Min,
+    #[default]
+    Median,
+    Mean,
+    P95,
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Statistic::Min => "min",
+            Statistic::Median => "median",
+            Statistic::Mean => "mean",
+            Statistic::P95 => "p95",
+        })
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = StatisticFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "min" => Ok(Statistic::Min),
+            "median" => Ok(Statistic::Median),
+            "mean" => Ok(Statistic::Mean),
+            "p95" => Ok(Statistic::P95),
+            _ => Err(StatisticFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Statistic`].
+#[derive(Debug)]
+pub struct StatisticFromStrError;
+
+impl std::error::Error for StatisticFromStrError {}
+
+impl Display for StatisticFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `min`, `median`, `mean` or `p95`")
+    }
+}
+
+/// Summary of the measured samples of a solution part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Computes the statistics for a non-empty set of samples.
+    ///
+    /// Samples outside of 1.5 times the interquartile range are counted as outliers
+    /// and excluded from mean and standard deviation.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics without samples"
+        );
+
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25);
+        let q3 = percentile(&sorted, 75);
+        let fence = (q3 - q1) * 3 / 2;
+        let (low, high) = (q1.saturating_sub(fence), q3 + fence);
+
+        let inliers: Vec<u128> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (low..=high).contains(x))
+            .collect();
+
+        let mean = inliers.iter().sum::<u128>() / inliers.len() as u128;
+        #[allow(clippy::cast_precision_loss)]
+        let variance = inliers
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / inliers.len() as f64;
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+
+        Self {
+            samples: sorted.len() as u128,
+            min: nanos_to_duration(sorted[0]),
+            median: nanos_to_duration(median),
+            p95: nanos_to_duration(percentile(&sorted, 95)),
+            mean: nanos_to_duration(mean),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            outliers: sorted.len() - inliers.len(),
+        }
+    }
+
+    /// Returns the value of the given statistic.
+    #[must_use]
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Min => self.min,
+            Statistic::Median => self.median,
+            Statistic::Mean => self.mean,
+            Statistic::P95 => self.p95,
+        }
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[u128], percent: usize) -> u128 {
+    let rank = (percent * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn nanos_to_duration(nanos: u128) -> Duration {
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(nanos as u64)
+}
+
+/// Parse the `--time` flag. The statistic that should be reported can be passed as `--time=<statistic>`.
+fn time_statistic(args: &[String]) -> Result<Option<Statistic>, Error> {
+    args.iter()
+        .find_map(|arg| {
+            if arg == "--time" {
+                return Some(Ok(Statistic::default()));
+            }
+
+            Some(arg.strip_prefix("--time=")?.parse().map_err(|e| {
+                Error::Usage(format!("unexpected command-line input for `--time`: {e}."))
+            }))
+        })
+        .transpose()
+}
+
+/// The input that a solution binary runs against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/<year>/examples`, selected with `--example`.
+    Example,
+    /// A file, selected with `--input <path>`.
+    File(String),
+    /// The standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Selects the input from the value of the `--input` option and the `--example` flag, which cannot be combined.
+    /// Shared by the solution binaries and `cargo solve`.
+    pub fn select(path: Option<String>, is_example: bool) -> Result<Self, Error> {
+        match path {
+            Some(_) if is_example => Err(Error::Usage(
+                "`--input` and `--example` cannot be combined.".into(),
+            )),
+            Some(path) if path.is_empty() => Err(Error::Usage(
+                "`--input` expects a path, or `-` to read the input from stdin.".into(),
+            )),
+            Some(path) if path == "-" => Ok(InputSource::Stdin),
+            Some(path) => Ok(InputSource::File(path)),
+            None if is_example => Ok(InputSource::Example),
+            None => Ok(InputSource::Puzzle),
+        }
+    }
+}
+
+/// Parse the `--input` option and the `--example` flag, which select the input of a solution binary.
+fn input_source(args: &[String]) -> Result<InputSource, Error> {
+    let is_example = args.iter().any(|arg| arg == "--example");
+    InputSource::select(option_value(args, "--input"), is_example)
+}
+
+/// Parse the `--submit <part>` option.
+fn submit_part(args: &[String]) -> Result<Option<u8>, Error> {
+    if !args.iter().any(|arg| arg == "--submit") {
+        return Ok(None);
+    }
+
+    args.iter()
+        .skip_while(|arg| *arg != "--submit")
+        .nth(1)
+        .and_then(|part| part.parse().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            Error::Usage("unexpected command-line input. Format: cargo solve 1 --submit 1".into())
+        })
+}
+
+/// The parameters of a solution binary: the defaults of the examples with `--example`,
+/// otherwise those of the puzzle input, also for a file or the standard input. See [`run_params`].
+fn binary_params<P: Params>(input: &InputSource, overrides: &[String]) -> Result<P, Error> {
+    run_params(*input == InputSource::Example, overrides)
+}
+
+/// The parameters of a run: the defaults of the examples or of the puzzle input,
+/// with the `overrides` passed via `--param name=value`, which may be given several times.
+fn run_params<P: Params>(is_example: bool, overrides: &[String]) -> Result<P, Error> {
+    let mut params = if is_example { P::example() } else { P::real() };
+
+    for param in overrides {
+        let Some((name, value)) = param.split_once('=') else {
+            return Err(Error::Usage(format!(
+                "unexpected command-line input for `--param`: expected `name=value`, got \"{param}\"."
+            )));
+        };
+
+        params.set(name.trim(), value.trim()).map_err(|reason| {
+            Error::Usage(format!("could not set parameter \"{name}\": {reason}"))
+        })?;
+    }
+
+    Ok(params)
+}
+
+/// The time budget of a day. A budget for the day in `AOC_TIMEOUT_<year>_<day>` takes precedence over
+/// the `timeout` passed via `--timeout <seconds>`, which in turn takes precedence over the default budget in `AOC_TIMEOUT`.
+fn time_budget(year: Year, day: Day, timeout: Option<TimeBudget>) -> Option<Duration> {
+    let variable_budget = |variable: &str| {
+        let value = env::var(variable).ok()?;
+        match value.parse::<TimeBudget>() {
+            Ok(budget) => Some(budget.0),
+            Err(e) => Error::Usage(format!("unexpected input for `{variable}`: {e}.")).exit(),
+        }
+    };
+
+    variable_budget(&format!("AOC_TIMEOUT_{year}_{day}"))
+        .or(timeout.map(|budget| budget.0))
+        .or_else(|| variable_budget("AOC_TIMEOUT"))
+}
+
+/// Parse an option whose value implements [`FromStr`].
+fn parse_option<T>(args: &[String], name: &str) -> Result<Option<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    option_value(args, name)
+        .as_deref()
+        .map(str::parse)
+        .transpose()
+        .map_err(|e| Error::Usage(format!("unexpected command-line input for `{name}`: {e}.")))
+}
+
+/// The value of an option that is passed either as `<name> <value>` or as `<name>=<value>`.
+fn option_value(args: &[String], name: &str) -> Option<String> {
+    option_values(args, name).into_iter().next()
+}
+
+/// Every value of an option that may be given several times.
+fn option_values(args: &[String], name: &str) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter_map(|(i, arg)| {
+            if arg == name {
+                return Some(args.get(i + 1).cloned().unwrap_or_default());
+            }
+            arg.strip_prefix(name)?
+                .strip_prefix('=')
+                .map(ToString::to_string)
+        })
+        .collect()
+}
+
+/// The format in which results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON record per solution part.
+    Json,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+        })
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl std::error::Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting either `text` or `json`")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PartStatus {
+    Solved,
+    /// The solution returned [`None`].
+    Unsolved,
+    /// The solution returned [`None`] for a part that the manifest lists as not solved yet.
+    Open,
+    /// The part exceeded its time budget.
+    TimedOut,
+    /// The result belongs to the parse step of a solution.
+    Parsed,
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Open => "open",
+            PartStatus::TimedOut => "timed-out",
+            PartStatus::Parsed => "parsed",
+        })
+    }
+}
+
+/// The outcome of running a single solution part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: Year,
+    pub day: Day,
+    /// The part number, `0` for the parse step.
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// The accepted answer from `data/answers`, if known.
+    pub expected: Option<String>,
+    /// The reported statistic of the timings.
+    pub duration: Duration,
+    pub stats: BenchStats,
+}
+
+impl PartResult {
+    #[must_use]
+    pub fn new(
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: Option<String>,
+        stats: BenchStats,
+        statistic: Statistic,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer,
+            expected: None,
+            duration: stats.get(statistic),
+            stats,
+        }
+    }
+
+    /// The result of the parse step of a solution.
+    #[must_use]
+    pub fn parse_step(year: Year, day: Day, stats: BenchStats, statistic: Statistic) -> Self {
+        Self {
+            year,
+            day,
+            part: 0,
+            status: PartStatus::Parsed,
+            answer: None,
+            expected: None,
+            duration: stats.get(statistic),
+            stats,
+        }
+    }
+
+    /// The result of a part that was aborted after exceeding its time budget.
+    #[must_use]
+    pub fn timed_out(year: Year, day: Day, part: u8, budget: Duration) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            status: PartStatus::TimedOut,
+            answer: None,
+            expected: answers::expected(year, day, part),
+            duration: budget,
+            stats: BenchStats::from_samples(&[budget]),
+        }
+    }
+
+    /// Compares the answer against the accepted answer.
+    #[must_use]
+    pub fn verification(&self) -> Verification {
+        if self.status == PartStatus::Parsed {
+            return Verification::Unknown;
+        }
+        answers::verify(self.expected.as_deref(), self.answer.as_deref())
+    }
+
+    /// Serializes the result into a single-line JSON record.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        let record = Record {
+            year: self.year.into_inner(),
+            day: self.day.into_inner(),
+            part: self.part,
+            status: self.status,
+            answer: self.answer.clone(),
+            expected: self.expected.clone(),
+            verification: self.verification(),
+            duration_nanos: nanos(self.duration),
+            samples: self.stats.samples,
+            min_nanos: nanos(self.stats.min),
+            median_nanos: nanos(self.stats.median),
+            p95_nanos: nanos(self.stats.p95),
+            mean_nanos: nanos(self.stats.mean),
+            std_dev_nanos: nanos(self.stats.std_dev),
+            outliers: self.stats.outliers,
+        };
+
+        // a record only holds strings and numbers, which always serialize.
+        serde_json::to_string(&record).unwrap_or_default()
+    }
+
+    /// Parses a JSON record as written by [`PartResult::to_json`].
+    /// Returns [`None`] if the line is not such a record.
+    #[must_use]
+    pub fn from_json(line: &str) -> Option<Self> {
+        let record: Record = serde_json::from_str(line).ok()?;
+
+        Some(Self {
+            year: Year::new(record.year)?,
+            day: Day::new(record.day)?,
+            part: record.part,
+            status: record.status,
+            answer: record.answer,
+            expected: record.expected,
+            duration: Duration::from_nanos(record.duration_nanos),
+            stats: BenchStats {
+                samples: record.samples,
+                min: Duration::from_nanos(record.min_nanos),
+                median: Duration::from_nanos(record.median_nanos),
+                p95: Duration::from_nanos(record.p95_nanos),
+                mean: Duration::from_nanos(record.mean_nanos),
+                std_dev: Duration::from_nanos(record.std_dev_nanos),
+                outliers: record.outliers,
+            },
+        })
+    }
+}
+
+/// The JSON record of a [`PartResult`] that is exchanged between the solution binaries and the CLI.
+/// The verification is derived from the answers, it is only written for other consumers of the records.
+#[derive(Serialize, Deserialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: PartStatus,
+    answer: Option<String>,
+    expected: Option<String>,
+    #[serde(skip_deserializing)]
+    verification: Verification,
+    duration_nanos: u64,
+    samples: u128,
+    min_nanos: u64,
+    median_nanos: u64,
+    p95_nanos: u64,
+    mean_nanos: u64,
+    std_dev_nanos: u64,
+    outliers: usize,
+}
+
+/// Prints the human-readable output for a solution part.
+pub fn print_part_result(result: &PartResult) {
+    let duration_str = format_duration(&result.duration, result.stats.samples);
+
+    if result.status == PartStatus::Parsed {
+        print!("\r");
+        println!("Parse:{duration_str}             ");
+    } else if result.status == PartStatus::Open {
+        print!("\r");
+        println!(
+            "Part {}: {ANSI_ITALIC}open{ANSI_RESET}{duration_str}             ",
+            result.part
+        );
+    } else if result.status == PartStatus::TimedOut {
+        print!("\r");
+        println!(
+            "Part {}: {ANSI_ITALIC}timed out{ANSI_RESET}{duration_str}             ",
+            result.part
+        );
+    } else {
+        let marker = match result.verification() {
+            Verification::Unknown => String::new(),
+            Verification::Correct => " ✔".into(),
+            Verification::Incorrect => format!(
+                " ✘ {ANSI_ITALIC}expected {}{ANSI_RESET}",
+                result.expected.as_deref().unwrap_or_default()
+            ),
+        };
+
+        print_result(
+            &result.answer,
+            &format!("Part {}", result.part),
+            &duration_str,
+            &marker,
+        );
+    }
+
+    if result.stats.samples > 1 {
+        print_stats(&result.stats);
+    }
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -92,13 +972,20 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_stats(stats: &BenchStats) {
+    println!(
+        "  {ANSI_ITALIC}min {:.1?} | median {:.1?} | p95 {:.1?} | mean {:.1?} ± {:.1?} | {} outliers{ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.mean, stats.std_dev, stats.outliers
+    );
+}
+
+fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str, marker: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+                let str = format!("{part}: ▼ {duration_str}{marker}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -107,7 +994,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     println!("{result}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}{marker}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -121,47 +1008,227 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{marker}             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Try to submit the answer of a part that was selected via `--submit <part>` for the puzzle input if:
+///  1. the previous submissions of the part do not rule the answer out, see [`submissions::check`].
+///  2. the backend selected by `AOC_BACKEND` is available, see [`aoc_backend::backend`].
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+) -> Option<Result<Verdict, Error>> {
+    let result = result.to_string();
 
-    if !args.contains(&"--submit".into()) {
+    if let Err(refusal) = submissions::check(year, day, part, &result) {
+        eprintln!(
+            "Not submitting part {part}: {refusal} See \"{}\".",
+            submissions::get_submissions_path(year, day)
+        );
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let backend = aoc_backend::backend().unwrap_or_else(|e| e.exit());
+
+    println!("Submitting result...");
+    let verdict = aoc_backend::submit(backend.as_ref(), year, day, part, &result);
+
+    if let Err(e) = &verdict {
+        eprintln!("Error: {e}");
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    Some(verdict)
+}
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        binary_params, run_params, BenchStats, InputSource, OutputFormat, PartResult, PartStatus,
+        RunSettings, Statistic, TimeBudget, Verification,
     };
+    use crate::template::params::Steps;
+    use crate::{day, year};
+    use std::time::Duration;
 
-    if part_submit != part {
-        return None;
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn stats_of_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn stats_order_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[
+            20, 1, 19, 2, 18, 3, 17, 4, 16, 5, 15, 6, 14, 7, 13, 8, 12, 9, 11, 10,
+        ]));
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(10));
+        assert_eq!(stats.p95, Duration::from_nanos(19));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn stats_reject_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 10, 10, 10, 10, 10, 10, 10, 10, 1000]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(10));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.get(Statistic::Median), Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn parse_statistic() {
+        assert_eq!("p95".parse::<Statistic>().unwrap(), Statistic::P95);
+        assert_eq!(Statistic::default().to_string(), "median");
+        assert!("avg".parse::<Statistic>().is_err());
+    }
+
+    #[test]
+    fn part_result_json_round_trip() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 12, 14]));
+        let solved = PartResult::new(
+            year!(2023),
+            day!(3),
+            1,
+            Some("@ (1ms @ 2 samples)".into()),
+            stats.clone(),
+            Statistic::Mean,
+        );
+        let unsolved = PartResult::new(year!(2023), day!(3), 2, None, stats, Statistic::Median);
+
+        assert_eq!(
+            PartResult::from_json(&solved.to_json()),
+            Some(solved.clone())
+        );
+        assert_eq!(
+            PartResult::from_json(&unsolved.to_json()),
+            Some(unsolved.clone())
+        );
+        assert_eq!(solved.status, PartStatus::Solved);
+        assert_eq!(unsolved.status, PartStatus::Unsolved);
+        assert_eq!(solved.duration, Duration::from_nanos(12));
+
+        let mut open = unsolved;
+        open.status = PartStatus::Open;
+        assert_eq!(PartResult::from_json(&open.to_json()), Some(open));
+
+        let mut multi_line = solved;
+        multi_line.answer = Some("#.\n\"#\" \\ \t ✔".into());
+        multi_line.status = PartStatus::TimedOut;
+        let json = multi_line.to_json();
+        assert!(!json.contains('\n'));
+        assert_eq!(PartResult::from_json(&json), Some(multi_line));
+
+        assert_eq!(PartResult::from_json("Part 1: 42 (1.0ms)"), None);
+        assert_eq!(PartResult::from_json(r#"{"year":2023}"#), None);
+    }
+
+    #[test]
+    fn part_result_verification() {
+        let stats = BenchStats::from_samples(&nanos(&[10]));
+        let mut result = PartResult::new(
+            year!(2023),
+            day!(3),
+            1,
+            Some("42".into()),
+            stats,
+            Statistic::Mean,
+        );
+        assert_eq!(result.verification(), Verification::Unknown);
+
+        result.expected = Some("42".into());
+        assert_eq!(result.verification(), Verification::Correct);
+        assert_eq!(
+            PartResult::from_json(&result.to_json()),
+            Some(result.clone())
+        );
+
+        result.expected = Some("41".into());
+        assert_eq!(result.verification(), Verification::Incorrect);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn parse_step_json_round_trip() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 12, 14]));
+        let parsed = PartResult::parse_step(year!(2023), day!(3), stats, Statistic::Min);
+
+        assert_eq!(
+            PartResult::from_json(&parsed.to_json()),
+            Some(parsed.clone())
+        );
+        assert_eq!(parsed.part, 0);
+        assert_eq!(parsed.status, PartStatus::Parsed);
+        assert_eq!(parsed.duration, Duration::from_nanos(10));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn parses_run_settings() {
+        let args = |args: &str| -> Vec<String> {
+            args.split_whitespace().map(ToString::to_string).collect()
+        };
+
+        assert_eq!(
+            RunSettings::parse(&args("2023-01")).unwrap(),
+            RunSettings::default()
+        );
+
+        let settings = RunSettings::parse(&args(
+            "2023-21 --time=p95 --format json --timeout=2.5 --part 2 --example --param steps=6 --param=grid=11 --submit 2",
+        ))
+        .unwrap();
+        assert_eq!(
+            settings,
+            RunSettings {
+                time: Some(Statistic::P95),
+                format: OutputFormat::Json,
+                timeout: Some(TimeBudget(Duration::from_millis(2500))),
+                part: Some(2),
+                input: InputSource::Example,
+                params: vec!["steps=6".into(), "grid=11".into()],
+                submit: Some(2),
+            }
+        );
+
+        assert_eq!(
+            RunSettings::parse(&args("2023-01 --time --input -"))
+                .unwrap()
+                .time,
+            Some(Statistic::Median)
+        );
+        assert!(RunSettings::parse(&args("2023-01 --input a.txt --example")).is_err());
+        assert!(RunSettings::parse(&args("2023-01 --part two")).is_err());
+        assert!(RunSettings::parse(&args("2023-01 --submit")).is_err());
+    }
+
+    #[test]
+    fn binary_params_follow_input_source() {
+        let params = |input: InputSource| binary_params::<Steps>(&input, &[]).unwrap().0;
+        assert_eq!(params(InputSource::Puzzle), 64);
+        assert_eq!(params(InputSource::Example), 6);
+        assert_eq!(params(InputSource::File("a.txt".into())), 64);
+        assert_eq!(params(InputSource::Stdin), 64);
+    }
+
+    #[test]
+    fn overrides_run_params() {
+        assert_eq!(run_params::<Steps>(true, &["steps=2".into()]).unwrap().0, 2);
+        assert!(run_params::<Steps>(false, &["steps".into()]).is_err());
+        assert!(run_params::<Steps>(false, &["lines=2".into()]).is_err());
+    }
 }
@@ -0,0 +1,470 @@
+/// Generic interface to the solutions, so that they can be enumerated and driven without knowing their concrete types.
+use std::{collections::BTreeMap, fmt::Display, marker::PhantomData};
+
+use crate::template::{
+    input::Input,
+    params::Params,
+    runner::{self, PartResult, RunSettings},
+    Error,
+};
+use crate::{Day, Year};
+
+/// The solution for a day of advent.
+pub trait Solution {
+    /// The parsed puzzle input, shared by all parts.
+    type Input<'a>;
+    /// The parameters that differ between the examples and the puzzle input, `()` if there are none.
+    type Params: Params;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn year(&self) -> Year;
+
+    fn day(&self) -> Day;
+
+    fn parse<'a>(&self, input: &'a Input) -> Self::Input<'a>;
+
+    /// Whether [`Solution::parse`] is a distinct step that is timed separately from the parts.
+    fn has_parse_step(&self) -> bool {
+        true
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>, params: &Self::Params) -> Option<Self::Answer1>;
+
+    fn part_two(&self, input: &Self::Input<'_>, params: &Self::Params) -> Option<Self::Answer2>;
+
+    /// The number of parts beyond part two, e.g. for visualizations.
+    fn extra_parts(&self) -> u8 {
+        0
+    }
+
+    /// Runs an extra part. Extra parts are numbered from 3 onwards.
+    fn extra_part(
+        &self,
+        _part: u8,
+        _input: &Self::Input<'_>,
+        _params: &Self::Params,
+    ) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe counterpart of [`Solution`] that is implemented for every solution.
+pub trait DynSolution {
+    fn year(&self) -> Year;
+
+    fn day(&self) -> Day;
+
+    /// Runs all parts against the input, see [`runner::run_solution`].
+    fn run(&self, input: &Input, settings: &RunSettings) -> Result<Vec<PartResult>, Error>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn year(&self) -> Year {
+        Solution::year(self)
+    }
+
+    fn day(&self) -> Day {
+        Solution::day(self)
+    }
+
+    fn run(&self, input: &Input, settings: &RunSettings) -> Result<Vec<PartResult>, Error> {
+        runner::run_solution(self, input, settings)
+    }
+}
+
+/// Solutions keyed by their year and day.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(Year, Day), Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a solution, replacing a previously registered solution for the same day.
+    pub fn register(&mut self, solution: impl Solution + 'static) -> &mut Self {
+        let key = (Solution::year(&solution), Solution::day(&solution));
+        self.solutions.insert(key, Box::new(solution));
+        self
+    }
+
+    #[must_use]
+    pub fn get(&self, year: Year, day: Day) -> Option<&dyn DynSolution> {
+        self.solutions.get(&(year, day)).map(AsRef::as_ref)
+    }
+
+    /// Iterates the registered days of a year in ascending order.
+    pub fn days(&self, year: Year) -> impl Iterator<Item = Day> + '_ {
+        self.solutions
+            .keys()
+            .filter(move |(y, _)| *y == year)
+            .map(|(_, day)| *day)
+    }
+}
+
+/// Adapts a pair of `part_one` / `part_two` functions operating on the [`Input`] to [`Solution`].
+/// This is what the [`solution!`](crate::solution) macro generates.
+pub struct FnSolution<P1, P2, A1, A2> {
+    year: Year,
+    day: Day,
+    part_one: P1,
+    part_two: P2,
+    answers: PhantomData<fn() -> (A1, A2)>,
+}
+
+impl<P1, P2, A1, A2> FnSolution<P1, P2, A1, A2>
+where
+    P1: Fn(&Input) -> Option<A1>,
+    P2: Fn(&Input) -> Option<A2>,
+{
+    pub fn new(year: Year, day: Day, part_one: P1, part_two: P2) -> Self {
+        Self {
+            year,
+            day,
+            part_one,
+            part_two,
+            answers: PhantomData,
+        }
+    }
+}
+
+impl<P1, P2, A1, A2> Solution for FnSolution<P1, P2, A1, A2>
+where
+    P1: Fn(&Input) -> Option<A1>,
+    P2: Fn(&Input) -> Option<A2>,
+    A1: Display,
+    A2: Display,
+{
+    type Input<'a> = &'a Input;
+    type Params = ();
+    type Answer1 = A1;
+    type Answer2 = A2;
+
+    fn year(&self) -> Year {
+        self.year
+    }
+
+    fn day(&self) -> Day {
+        self.day
+    }
+
+    fn parse<'a>(&self, input: &'a Input) -> Self::Input<'a> {
+        input
+    }
+
+    fn has_parse_step(&self) -> bool {
+        false
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>, _params: &()) -> Option<A1> {
+        (self.part_one)(input)
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>, _params: &()) -> Option<A2> {
+        (self.part_two)(input)
+    }
+}
+
+/// Adapts a pair of `part_one` / `part_two` functions operating on the [`Input`] and a set of [`Params`] to [`Solution`].
+/// This is what the [`solution!`](crate::solution) macro generates for `solution!(2023, 21, params: Steps)`.
+pub struct ParamFnSolution<P, P1, P2, A1, A2> {
+    year: Year,
+    day: Day,
+    part_one: P1,
+    part_two: P2,
+    params: PhantomData<fn() -> P>,
+    answers: PhantomData<fn() -> (A1, A2)>,
+}
+
+impl<P, P1, P2, A1, A2> ParamFnSolution<P, P1, P2, A1, A2>
+where
+    P: Params,
+    P1: Fn(&Input, &P) -> Option<A1>,
+    P2: Fn(&Input, &P) -> Option<A2>,
+{
+    pub fn new(year: Year, day: Day, part_one: P1, part_two: P2) -> Self {
+        Self {
+            year,
+            day,
+            part_one,
+            part_two,
+            params: PhantomData,
+            answers: PhantomData,
+        }
+    }
+}
+
+impl<P, P1, P2, A1, A2> Solution for ParamFnSolution<P, P1, P2, A1, A2>
+where
+    P: Params,
+    P1: Fn(&Input, &P) -> Option<A1>,
+    P2: Fn(&Input, &P) -> Option<A2>,
+    A1: Display,
+    A2: Display,
+{
+    type Input<'a> = &'a Input;
+    type Params = P;
+    type Answer1 = A1;
+    type Answer2 = A2;
+
+    fn year(&self) -> Year {
+        self.year
+    }
+
+    fn day(&self) -> Day {
+        self.day
+    }
+
+    fn parse<'a>(&self, input: &'a Input) -> Self::Input<'a> {
+        input
+    }
+
+    fn has_parse_step(&self) -> bool {
+        false
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>, params: &P) -> Option<A1> {
+        (self.part_one)(input, params)
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>, params: &P) -> Option<A2> {
+        (self.part_two)(input, params)
+    }
+}
+
+/// Adapts a parse function and `part_one` / `part_two` functions operating on its result to [`Solution`].
+/// The parsed value is shared by both parts. It cannot borrow from the raw input; implement [`Solution`] directly for that.
+pub struct ParsedFnSolution<P, P1, P2, T, A1, A2> {
+    year: Year,
+    day: Day,
+    parse: P,
+    part_one: P1,
+    part_two: P2,
+    input: PhantomData<fn() -> T>,
+    answers: PhantomData<fn() -> (A1, A2)>,
+}
+
+impl<P, P1, P2, T, A1, A2> ParsedFnSolution<P, P1, P2, T, A1, A2>
+where
+    P: Fn(&Input) -> T,
+    P1: Fn(&T) -> Option<A1>,
+    P2: Fn(&T) -> Option<A2>,
+{
+    pub fn new(year: Year, day: Day, parse: P, part_one: P1, part_two: P2) -> Self {
+        Self {
+            year,
+            day,
+            parse,
+            part_one,
+            part_two,
+            input: PhantomData,
+            answers: PhantomData,
+        }
+    }
+}
+
+impl<P, P1, P2, T, A1, A2> Solution for ParsedFnSolution<P, P1, P2, T, A1, A2>
+where
+    P: Fn(&Input) -> T,
+    P1: Fn(&T) -> Option<A1>,
+    P2: Fn(&T) -> Option<A2>,
+    A1: Display,
+    A2: Display,
+{
+    type Input<'a> = T;
+    type Params = ();
+    type Answer1 = A1;
+    type Answer2 = A2;
+
+    fn year(&self) -> Year {
+        self.year
+    }
+
+    fn day(&self) -> Day {
+        self.day
+    }
+
+    fn parse<'a>(&self, input: &'a Input) -> Self::Input<'a> {
+        (self.parse)(input)
+    }
+
+    fn part_one(&self, input: &T, _params: &()) -> Option<A1> {
+        (self.part_one)(input)
+    }
+
+    fn part_two(&self, input: &T, _params: &()) -> Option<A2> {
+        (self.part_two)(input)
+    }
+}
+
+/// Adapts a parse function and `part_one` / `part_two` functions operating on its result and a set of [`Params`] to [`Solution`].
+/// This is what the [`solution!`](crate::solution) macro generates for `solution!(2023, 24, parse, params: TestArea)`.
+pub struct ParsedParamFnSolution<P, F, P1, P2, T, A1, A2> {
+    year: Year,
+    day: Day,
+    parse: F,
+    part_one: P1,
+    part_two: P2,
+    params: PhantomData<fn() -> P>,
+    input: PhantomData<fn() -> T>,
+    answers: PhantomData<fn() -> (A1, A2)>,
+}
+
+impl<P, F, P1, P2, T, A1, A2> ParsedParamFnSolution<P, F, P1, P2, T, A1, A2>
+where
+    P: Params,
+    F: Fn(&Input) -> T,
+    P1: Fn(&T, &P) -> Option<A1>,
+    P2: Fn(&T, &P) -> Option<A2>,
+{
+    pub fn new(year: Year, day: Day, parse: F, part_one: P1, part_two: P2) -> Self {
+        Self {
+            year,
+            day,
+            parse,
+            part_one,
+            part_two,
+            params: PhantomData,
+            input: PhantomData,
+            answers: PhantomData,
+        }
+    }
+}
+
+impl<P, F, P1, P2, T, A1, A2> Solution for ParsedParamFnSolution<P, F, P1, P2, T, A1, A2>
+where
+    P: Params,
+    F: Fn(&Input) -> T,
+    P1: Fn(&T, &P) -> Option<A1>,
+    P2: Fn(&T, &P) -> Option<A2>,
+    A1: Display,
+    A2: Display,
+{
+    type Input<'a> = T;
+    type Params = P;
+    type Answer1 = A1;
+    type Answer2 = A2;
+
+    fn year(&self) -> Year {
+        self.year
+    }
+
+    fn day(&self) -> Day {
+        self.day
+    }
+
+    fn parse<'a>(&self, input: &'a Input) -> Self::Input<'a> {
+        (self.parse)(input)
+    }
+
+    fn part_one(&self, input: &T, params: &P) -> Option<A1> {
+        (self.part_one)(input, params)
+    }
+
+    fn part_two(&self, input: &T, params: &P) -> Option<A2> {
+        (self.part_two)(input, params)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        FnSolution, ParamFnSolution, ParsedFnSolution, ParsedParamFnSolution, Registry, Solution,
+    };
+    use crate::template::{
+        input::Input,
+        params::{Params, Steps},
+    };
+    use crate::{day, year};
+
+    fn count_lines(input: &Input) -> Option<usize> {
+        Some(input.lines().count())
+    }
+
+    fn unsolved(_: &Input) -> Option<u32> {
+        None
+    }
+
+    #[test]
+    fn fn_solution_calls_parts() {
+        let solution = FnSolution::new(year!(2023), day!(1), count_lines, unsolved);
+        let input = solution.parse(Input::new("a\nb\nc"));
+        assert_eq!(solution.part_one(&input, &()), Some(3));
+        assert_eq!(solution.part_two(&input, &()), None);
+        assert_eq!(solution.extra_parts(), 0);
+        assert!(!solution.has_parse_step());
+    }
+
+    #[test]
+    fn parsed_fn_solution_shares_input() {
+        let solution = ParsedFnSolution::new(
+            year!(2023),
+            day!(1),
+            |input: &Input| input.lines().map(str::len).collect::<Vec<_>>(),
+            |lengths: &Vec<usize>| lengths.iter().max().copied(),
+            |lengths: &Vec<usize>| Some(lengths.iter().sum::<usize>()),
+        );
+        let input = solution.parse(Input::new("a\nbbb\ncc"));
+        assert_eq!(solution.part_one(&input, &()), Some(3));
+        assert_eq!(solution.part_two(&input, &()), Some(6));
+        assert!(solution.has_parse_step());
+    }
+
+    #[test]
+    fn param_fn_solution_passes_params() {
+        let solution = ParamFnSolution::new(
+            year!(2023),
+            day!(1),
+            |input: &Input, steps: &Steps| Some(input.lines().count() * steps.0),
+            |_: &Input, _: &Steps| None::<u32>,
+        );
+        let input = solution.parse(Input::new("a\nb\nc"));
+        assert_eq!(solution.part_one(&input, &Steps::example()), Some(18));
+        assert_eq!(solution.part_one(&input, &Steps::real()), Some(192));
+        assert!(!solution.has_parse_step());
+    }
+
+    #[test]
+    fn parsed_param_fn_solution_passes_params() {
+        let solution = ParsedParamFnSolution::new(
+            year!(2023),
+            day!(1),
+            |input: &Input| input.lines().map(str::len).collect::<Vec<_>>(),
+            |lengths: &Vec<usize>, steps: &Steps| Some(lengths.iter().sum::<usize>() * steps.0),
+            |_: &Vec<usize>, _: &Steps| None::<u32>,
+        );
+        let input = solution.parse(Input::new("a\nbbb\ncc"));
+        assert_eq!(solution.part_one(&input, &Steps::example()), Some(36));
+        assert_eq!(solution.part_one(&input, &Steps::real()), Some(384));
+        assert!(solution.has_parse_step());
+    }
+
+    #[test]
+    fn registry_is_keyed_by_year_and_day() {
+        let mut registry = Registry::new();
+        registry
+            .register(FnSolution::new(
+                year!(2023),
+                day!(12),
+                count_lines,
+                unsolved,
+            ))
+            .register(FnSolution::new(year!(2023), day!(3), count_lines, unsolved))
+            .register(FnSolution::new(year!(2022), day!(5), count_lines, unsolved));
+
+        assert_eq!(
+            registry.days(year!(2023)).collect::<Vec<_>>(),
+            vec![day!(3), day!(12)]
+        );
+        assert_eq!(
+            registry.get(year!(2023), day!(3)).map(|s| s.day()),
+            Some(day!(3))
+        );
+        assert!(registry.get(year!(2023), day!(5)).is_none());
+        assert!(registry.get(year!(2022), day!(5)).is_some());
+    }
+}
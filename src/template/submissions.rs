@@ -0,0 +1,376 @@
+/// Module that keeps a local log of submitted answers in `data/<year>/submissions/<day>.txt`.
+/// The log is used to refuse answers that are already known to be wrong before submitting them again.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{data_dir, get_data_path};
+use crate::{Day, Year};
+
+/// The verdict of the Advent of Code website for a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint whether it is too high or too low.
+    Incorrect,
+    /// The answer was not checked because of the rate limit. Holds the time left to wait, if known.
+    RateLimited(Option<Duration>),
+    /// The part was already completed, so the answer was not checked.
+    AlreadyCompleted,
+    /// The response of the configured backend could not be interpreted.
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited(None) => write!(f, "rate-limited"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate-limited:{}", wait.as_secs()),
+            Verdict::AlreadyCompleted => write!(f, "already-completed"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Interprets the response text of a submission.
+    #[must_use]
+    pub fn from_response(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait_time(text))
+        } else if text.contains("Did you already complete it?") {
+            Verdict::AlreadyCompleted
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            "rate-limited" => Verdict::RateLimited(None),
+            "already-completed" => Verdict::AlreadyCompleted,
+            "unknown" => Verdict::Unknown,
+            s => Verdict::RateLimited(Some(Duration::from_secs(
+                s.strip_prefix("rate-limited:")?.parse().ok()?,
+            ))),
+        })
+    }
+
+    /// Whether the verdict tells that the submitted answer is wrong.
+    #[must_use]
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+/// Parses the wait time from a text like "You have 1m 30s left to wait.".
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("You have ")?;
+
+    time.split_whitespace()
+        .map(|unit| {
+            let (value, factor) = if let Some(v) = unit.strip_suffix('h') {
+                (v, 3600)
+            } else if let Some(v) = unit.strip_suffix('m') {
+                (v, 60)
+            } else {
+                (unit.strip_suffix('s')?, 1)
+            };
+            value.parse::<u64>().ok().map(|v| v * factor)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(4, '\t');
+        Some(Self {
+            timestamp: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            verdict: Verdict::parse(fields.next()?)?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.timestamp, self.part, self.verdict, self.answer
+        )
+    }
+}
+
+/// Reasons for refusing to submit an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    AlreadyRejected(Verdict),
+    NotBelow(String),
+    NotAbove(String),
+    RateLimited(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Refusal::AlreadyRejected(verdict) => {
+                write!(f, "this answer was already rejected ({verdict}).")
+            }
+            Refusal::NotBelow(bound) => {
+                write!(f, "answer {bound} was too high, this answer is not lower.")
+            }
+            Refusal::NotAbove(bound) => {
+                write!(f, "answer {bound} was too low, this answer is not higher.")
+            }
+            Refusal::RateLimited(wait) => {
+                write!(f, "rate limited for another {}s.", wait.as_secs())
+            }
+        }
+    }
+}
+
+#[must_use]
+pub fn get_submissions_path(year: Year, day: Day) -> String {
+    get_data_path("submissions", year, day)
+}
+
+/// Reads the submission log of a day, oldest first. A missing file yields no submissions.
+#[must_use]
+pub fn read(year: Year, day: Day) -> Vec<Submission> {
+    fs::read_to_string(get_submissions_path(year, day))
+        .map(|content| content.lines().filter_map(Submission::parse).collect())
+        .unwrap_or_default()
+}
+
+/// Appends a submission to the log of a day.
+pub fn record(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+    verdict: Verdict,
+) -> Result<(), io::Error> {
+    let submission = Submission {
+        timestamp: now(),
+        part,
+        answer: answer.to_string(),
+        verdict,
+    };
+
+    fs::create_dir_all(data_dir::path(format!("{year}/submissions")))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_submissions_path(year, day))?;
+    writeln!(file, "{submission}")
+}
+
+/// Checks an answer against previous submissions of a part before it is submitted.
+pub fn check(year: Year, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+    check_against(&read(year, day), part, answer, now())
+}
+
+fn check_against(
+    submissions: &[Submission],
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<(), Refusal> {
+    let submissions: Vec<&Submission> = submissions.iter().filter(|s| s.part == part).collect();
+
+    if let Some(correct) = submissions.iter().find(|s| s.verdict == Verdict::Correct) {
+        return Err(Refusal::AlreadyCorrect(correct.answer.clone()));
+    }
+
+    if let Some(rejected) = submissions
+        .iter()
+        .find(|s| s.answer == answer && s.verdict.is_wrong())
+    {
+        return Err(Refusal::AlreadyRejected(rejected.verdict.clone()));
+    }
+
+    if let Some(Submission {
+        timestamp,
+        verdict: Verdict::RateLimited(Some(wait)),
+        ..
+    }) = submissions.last()
+    {
+        let until = timestamp + wait.as_secs();
+        if now < until {
+            return Err(Refusal::RateLimited(Duration::from_secs(until - now)));
+        }
+    }
+
+    let Ok(value) = answer.parse::<i128>() else {
+        return Ok(());
+    };
+
+    let bound = |verdict: Verdict| {
+        submissions
+            .iter()
+            .filter(move |s| s.verdict == verdict)
+            .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+    };
+
+    if let Some((_, too_high)) = bound(Verdict::TooHigh).filter(|(b, _)| value >= *b).min() {
+        return Err(Refusal::NotBelow(too_high.clone()));
+    }
+
+    if let Some((_, too_low)) = bound(Verdict::TooLow).filter(|(b, _)| value <= *b).max() {
+        return Err(Refusal::NotAbove(too_low.clone()));
+    }
+
+    Ok(())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_against, Refusal, Submission, Verdict};
+    use std::time::Duration;
+
+    fn submission(timestamp: u64, part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            timestamp,
+            part,
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 30s left to wait."),
+            Verdict::RateLimited(Some(Duration::from_secs(90)))
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Verdict::AlreadyCompleted
+        );
+        assert_eq!(Verdict::from_response("???"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let s = submission(
+            10,
+            2,
+            "a b",
+            Verdict::RateLimited(Some(Duration::from_secs(39))),
+        );
+        assert_eq!(Submission::parse(&s.to_string()), Some(s));
+        let s = submission(10, 1, "42", Verdict::TooLow);
+        assert_eq!(Submission::parse(&s.to_string()), Some(s));
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let log = [
+            submission(1, 1, "50", Verdict::TooHigh),
+            submission(2, 1, "10", Verdict::TooLow),
+            submission(3, 1, "30", Verdict::Incorrect),
+            submission(4, 2, "7", Verdict::Correct),
+        ];
+
+        assert_eq!(check_against(&log, 1, "20", 100), Ok(()));
+        assert_eq!(check_against(&log, 1, "abc", 100), Ok(()));
+        assert_eq!(
+            check_against(&log, 1, "30", 100),
+            Err(Refusal::AlreadyRejected(Verdict::Incorrect))
+        );
+        assert_eq!(
+            check_against(&log, 1, "60", 100),
+            Err(Refusal::NotBelow("50".into()))
+        );
+        assert_eq!(
+            check_against(&log, 1, "5", 100),
+            Err(Refusal::NotAbove("10".into()))
+        );
+        assert_eq!(
+            check_against(&log, 2, "8", 100),
+            Err(Refusal::AlreadyCorrect("7".into()))
+        );
+    }
+
+    #[test]
+    fn refuses_while_rate_limited() {
+        let log = [submission(
+            100,
+            1,
+            "5",
+            Verdict::RateLimited(Some(Duration::from_secs(60))),
+        )];
+
+        assert_eq!(
+            check_against(&log, 1, "5", 130),
+            Err(Refusal::RateLimited(Duration::from_secs(30)))
+        );
+        assert_eq!(check_against(&log, 1, "5", 160), Ok(()));
+    }
+}
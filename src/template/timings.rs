@@ -0,0 +1,381 @@
+/// Module that keeps the history of timed runs in `data/<year>/benchmarks.txt`,
+/// so that the last timings can be shown without running the solutions again and slower solutions can be detected.
+/// Each line holds the duration of one part of a run, separated by tabs: `<timestamp>\t<commit>\t<baseline>\t<day>\t<part>\t<nanos>\t<samples>`.
+/// The commit is the output of `git describe --always --dirty`. A missing commit or baseline name is written as `-`.
+/// Lines of older runs may lack the number of samples.
+/// The file is ignored by git, as timings depend on the machine, so the history and its named baselines are local to a clone.
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{
+    create_parent_dir, data_dir,
+    runner::{PartResult, PartStatus},
+    Error,
+};
+use crate::{Day, Year};
+
+/// The last known timings of both parts of a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LastTiming {
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+/// A timed run of the solutions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    /// The name under which the run was saved as baseline.
+    pub baseline: Option<String>,
+}
+
+impl Run {
+    /// A run that starts now, at the currently checked out commit.
+    #[must_use]
+    pub fn start(baseline: Option<String>) -> Self {
+        let commit = Command::new("git")
+            .args(["describe", "--always", "--dirty"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|commit| commit.trim().to_string())
+            .filter(|commit| !commit.is_empty());
+
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            commit,
+            baseline,
+        }
+    }
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.baseline, &self.commit) {
+            (Some(baseline), _) => write!(f, "baseline \"{baseline}\""),
+            (None, Some(commit)) => write!(f, "the run at {commit}"),
+            (None, None) => write!(f, "the previous run"),
+        }
+    }
+}
+
+/// The duration of one part in a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub run: Run,
+    pub day: Day,
+    /// The part number, `0` for the parse step.
+    pub part: u8,
+    pub duration: Duration,
+    /// The number of samples the duration was measured with, if known.
+    pub samples: Option<u128>,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Option<Self> {
+        let optional = |value: &str| (value != "-").then(|| value.to_string());
+        let mut fields = line.split('\t');
+
+        Some(Self {
+            run: Run {
+                timestamp: fields.next()?.parse().ok()?,
+                commit: optional(fields.next()?),
+                baseline: optional(fields.next()?),
+            },
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            duration: Duration::from_nanos(fields.next()?.parse().ok()?),
+            samples: match fields.next() {
+                Some(samples) => Some(samples.parse().ok()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.run.timestamp,
+            self.run.commit.as_deref().unwrap_or("-"),
+            self.run.baseline.as_deref().unwrap_or("-"),
+            self.day,
+            self.part,
+            self.duration.as_nanos()
+        )?;
+
+        match self.samples {
+            Some(samples) => write!(f, "\t{samples}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The run that the current timings are compared against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Baseline {
+    /// The most recent run.
+    Previous,
+    /// The most recent run that was saved under this name.
+    Named(String),
+}
+
+/// The change of the timing of one part between the baseline and the current run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delta {
+    pub day: Day,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Delta {
+    /// The change relative to the baseline, in percent.
+    #[must_use]
+    pub fn change_percent(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let (before, after) = (self.before.as_nanos() as f64, self.after.as_nanos() as f64);
+        (after - before) / before.max(1.0) * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+#[must_use]
+pub fn get_history_path(year: Year) -> String {
+    data_dir::path(format!("{year}/benchmarks.txt"))
+}
+
+/// Reads every entry of the history of a year, oldest first. A missing file yields no entries.
+#[must_use]
+pub fn read_history(year: Year) -> Vec<Entry> {
+    fs::read_to_string(get_history_path(year))
+        .map(|content| content.lines().filter_map(Entry::parse).collect())
+        .unwrap_or_default()
+}
+
+/// Reads the last known timings of every day of a year.
+#[must_use]
+pub fn read(year: Year) -> BTreeMap<Day, LastTiming> {
+    last_timings(&read_history(year))
+}
+
+/// Appends the timings of the solved parts of a run to the history.
+pub fn record(year: Year, run: &Run, results: &[(Day, Vec<PartResult>)]) -> Result<(), Error> {
+    let path = get_history_path(year);
+    create_parent_dir(&path)?;
+
+    let lines: String = entries(run, results)
+        .map(|entry| format!("{entry}\n"))
+        .collect();
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(Error::io(path))
+}
+
+/// Compares the results of a run with the baseline in the history.
+/// Returns [`None`] if there is no such baseline, otherwise the baseline and the deltas of all parts that were timed in both runs.
+#[must_use]
+pub fn compare(
+    history: &[Entry],
+    baseline: &Baseline,
+    results: &[(Day, Vec<PartResult>)],
+) -> Option<(Run, Vec<Delta>)> {
+    let run = history
+        .iter()
+        .rev()
+        .map(|entry| &entry.run)
+        .find(|run| match baseline {
+            Baseline::Previous => true,
+            Baseline::Named(name) => run.baseline.as_ref() == Some(name),
+        })?;
+
+    let before: BTreeMap<(Day, u8), Duration> = history
+        .iter()
+        .filter(|entry| entry.run == *run)
+        .map(|entry| ((entry.day, entry.part), entry.duration))
+        .collect();
+
+    let deltas = entries(run, results)
+        .filter_map(|entry| {
+            Some(Delta {
+                day: entry.day,
+                part: entry.part,
+                before: *before.get(&(entry.day, entry.part))?,
+                after: entry.duration,
+            })
+        })
+        .collect();
+
+    Some((run.clone(), deltas))
+}
+
+/// The most recent entry of every part of every day.
+#[must_use]
+pub fn latest(history: &[Entry]) -> BTreeMap<(Day, u8), &Entry> {
+    history
+        .iter()
+        .map(|entry| ((entry.day, entry.part), entry))
+        .collect()
+}
+
+fn entries<'a>(
+    run: &'a Run,
+    results: &'a [(Day, Vec<PartResult>)],
+) -> impl Iterator<Item = Entry> + 'a {
+    results.iter().flat_map(move |(day, results)| {
+        results
+            .iter()
+            .filter(|result| matches!(result.status, PartStatus::Solved | PartStatus::Parsed))
+            .map(move |result| Entry {
+                run: run.clone(),
+                day: *day,
+                part: result.part,
+                duration: result.duration,
+                samples: Some(result.stats.samples),
+            })
+    })
+}
+
+/// Merges the most recent timing of each part, so that a run of a single part keeps the timing of the other part.
+fn last_timings(history: &[Entry]) -> BTreeMap<Day, LastTiming> {
+    let mut timings: BTreeMap<Day, LastTiming> = BTreeMap::new();
+
+    for ((day, part), entry) in latest(history) {
+        let timing = timings.entry(day).or_default();
+        match part {
+            1 => timing.part_1 = Some(entry.duration),
+            2 => timing.part_2 = Some(entry.duration),
+            _ => {}
+        }
+    }
+
+    timings
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, last_timings, Baseline, Entry, Run};
+    use crate::template::runner::{BenchStats, PartResult, Statistic};
+    use crate::{day, year, Day};
+    use std::time::Duration;
+
+    fn run(timestamp: u64, baseline: Option<&str>) -> Run {
+        Run {
+            timestamp,
+            commit: Some("abc1234".into()),
+            baseline: baseline.map(Into::into),
+        }
+    }
+
+    fn entry(run: &Run, day: Day, part: u8, millis: u64) -> Entry {
+        Entry {
+            run: run.clone(),
+            day,
+            part,
+            duration: Duration::from_millis(millis),
+            samples: Some(10),
+        }
+    }
+
+    fn result(day: Day, part: u8, millis: u64) -> PartResult {
+        let stats = BenchStats::from_samples(&[Duration::from_millis(millis)]);
+        PartResult::new(
+            year!(2023),
+            day,
+            part,
+            Some("42".into()),
+            stats,
+            Statistic::Median,
+        )
+    }
+
+    #[test]
+    fn parses_entries() {
+        let line = "1701388800\tabc1234-dirty\t-\t17\t2\t250000000";
+        let entry = Entry::parse(line).unwrap();
+        assert_eq!(entry.run.commit.as_deref(), Some("abc1234-dirty"));
+        assert_eq!(entry.run.baseline, None);
+        assert_eq!(entry.day, day!(17));
+        assert_eq!(entry.duration, Duration::from_millis(250));
+        assert_eq!(entry.samples, None);
+        assert_eq!(entry.to_string(), line);
+        assert_eq!(Entry::parse("1701388800\tabc1234\t-\t17"), None);
+
+        let line = "1701388800\t-\tbefore\t01\t0\t1000\t500";
+        let entry = Entry::parse(line).unwrap();
+        assert_eq!(entry.run.baseline.as_deref(), Some("before"));
+        assert_eq!(entry.samples, Some(500));
+        assert_eq!(entry.to_string(), line);
+    }
+
+    #[test]
+    fn keeps_timings_of_last_run_per_part() {
+        let (first, second) = (run(1, None), run(2, None));
+        let history = [
+            entry(&first, day!(1), 1, 10),
+            entry(&first, day!(1), 2, 20),
+            entry(&first, day!(2), 1, 30),
+            entry(&second, day!(1), 1, 15),
+        ];
+
+        let timings = last_timings(&history);
+        assert_eq!(timings[&day!(1)].part_1, Some(Duration::from_millis(15)));
+        assert_eq!(timings[&day!(1)].part_2, Some(Duration::from_millis(20)));
+        assert_eq!(timings[&day!(2)].part_1, Some(Duration::from_millis(30)));
+    }
+
+    #[test]
+    fn compares_with_baseline() {
+        let (named, previous) = (run(1, Some("before")), run(2, None));
+        let history = [
+            entry(&named, day!(17), 1, 100),
+            entry(&named, day!(17), 2, 100),
+            entry(&previous, day!(17), 2, 200),
+        ];
+        let results = [(
+            day!(17),
+            vec![result(day!(17), 1, 100), result(day!(17), 2, 120)],
+        )];
+
+        let (baseline, deltas) = compare(&history, &Baseline::Previous, &results).unwrap();
+        assert_eq!(baseline, previous);
+        assert_eq!(deltas.len(), 1);
+        assert!(!deltas[0].is_regression(10.0));
+
+        let name = Baseline::Named("before".into());
+        let (baseline, deltas) = compare(&history, &name, &results).unwrap();
+        assert_eq!(baseline, named);
+        assert_eq!(deltas.len(), 2);
+        assert!(!deltas[0].is_regression(10.0));
+        assert!(deltas[1].is_regression(10.0));
+        assert!((deltas[1].change_percent() - 20.0).abs() < 1e-9);
+
+        assert_eq!(
+            compare(&history, &Baseline::Named("x".into()), &results),
+            None
+        );
+    }
+}
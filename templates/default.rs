@@ -0,0 +1,29 @@
+// {{title}}
+use advent_of_code::template::Input;
+
+advent_of_code::solution!({{year}}, {{day}});
+
+pub fn part_one(input: &Input) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(input: &Input) -> Option<{{answer_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
@@ -0,0 +1,59 @@
+// {{title}}
+use advent_of_code::template::Input;
+use std::collections::HashMap;
+
+advent_of_code::solution!({{year}}, {{day}}, Graph::from);
+
+pub fn part_one(graph: &Graph) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(graph: &Graph) -> Option<{{answer_type}}> {
+    None
+}
+
+/// The puzzle input as an adjacency list, parsed from lines like `a: b c`.
+pub struct Graph {
+    edges: HashMap<String, Vec<String>>,
+}
+
+impl From<&Input> for Graph {
+    fn from(input: &Input) -> Self {
+        let edges = input
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(node, targets)| {
+                let targets = targets.split_whitespace().map(String::from).collect();
+                (node.trim().to_string(), targets)
+            })
+            .collect();
+        Self { edges }
+    }
+}
+
+impl Graph {
+    pub fn neighbours(&self, node: &str) -> &[String] {
+        self.edges.get(node).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&Graph::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&Graph::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
+        assert_eq!(result, None);
+    }
+}
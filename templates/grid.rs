@@ -0,0 +1,65 @@
+// {{title}}
+use advent_of_code::template::Input;
+
+advent_of_code::solution!({{year}}, {{day}}, Grid::from);
+
+pub fn part_one(grid: &Grid) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(grid: &Grid) -> Option<{{answer_type}}> {
+    None
+}
+
+/// The puzzle input as a rectangle of characters.
+pub struct Grid {
+    cells: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl From<&Input> for Grid {
+    fn from(input: &Input) -> Self {
+        let width = input.lines().next().map_or(0, str::len);
+        let cells: Vec<u8> = input.lines().flat_map(str::bytes).collect();
+        let height = cells.len().checked_div(width).unwrap_or_default();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl Grid {
+    pub fn get(&self, x: isize, y: isize) -> Option<u8> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// The positions of the four orthogonal neighbours, which might lie outside of the grid.
+    pub fn neighbours(x: isize, y: isize) -> [(isize, isize); 4] {
+        [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&Grid::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&Grid::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
+        assert_eq!(result, None);
+    }
+}
@@ -0,0 +1,55 @@
+// {{title}}
+use advent_of_code::template::Input;
+
+advent_of_code::solution!({{year}}, {{day}}, Puzzle::from);
+
+pub fn part_one(puzzle: &Puzzle) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(puzzle: &Puzzle) -> Option<{{answer_type}}> {
+    None
+}
+
+/// The parsed puzzle input, shared by both parts.
+pub struct Puzzle {
+    items: Vec<Item>,
+}
+
+/// One line of the puzzle input.
+pub struct Item {}
+
+impl From<&Input> for Puzzle {
+    fn from(input: &Input) -> Self {
+        Self {
+            items: input.lines().map(Item::from).collect(),
+        }
+    }
+}
+
+impl From<&str> for Item {
+    fn from(line: &str) -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&Puzzle::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&Puzzle::from(
+            advent_of_code::template::read_file("examples", YEAR, DAY).as_input(),
+        ));
+        assert_eq!(result, None);
+    }
+}